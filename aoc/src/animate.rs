@@ -0,0 +1,156 @@
+use aoc_common::grid::Grid;
+use aoc_common::simulation::Simulation;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+pub const CONTROLS: &str = "Enter: pause or step, N: skip N steps, p: play, q: quit";
+
+// ANSI escapes
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_LINE_END: &str = "\x1b[K";
+const CLEAR_BELOW: &str = "\x1b[J";
+const HIGHLIGHT: &str = "\x1b[1;30;43m";
+const RESET: &str = "\x1b[0m";
+
+pub struct AnimateOptions {
+	pub delay: Duration,
+	// steps taken before the first frame is drawn
+	pub skip: usize,
+	// waits for a control before every step
+	pub paused: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Control {
+	Enter,
+	Skip(usize),
+	Play,
+	Quit,
+}
+
+fn parse_control(line: &str) -> Option<Control> {
+	match line.trim() {
+		"" => Some(Control::Enter),
+		"p" => Some(Control::Play),
+		"q" => Some(Control::Quit),
+		steps => steps.parse().ok().map(Control::Skip),
+	}
+}
+
+// stdin is read on its own thread, so that playing doesn't block on it. Lines that are not
+// controls are ignored
+fn read_controls() -> Receiver<Control> {
+	let (sender, receiver) = mpsc::channel();
+	thread::spawn(move || {
+		for line in io::stdin().lock().lines() {
+			let Ok(line) = line else { break };
+			if let Some(control) = parse_control(&line) && sender.send(control).is_err() {
+				break;
+			}
+		}
+	});
+	receiver
+}
+
+// the frame drawn over the previous one, with the cells that changed since then highlighted
+fn draw_frame(frame: &Grid<char>, previous: Option<&Grid<char>>, status: &str) -> String {
+	let mut text = CURSOR_HOME.to_string();
+	for (row, cells) in frame.rows().enumerate() {
+		for (column, cell) in cells.iter().enumerate() {
+			let changed = previous
+				.and_then(|previous| previous.get((row as i32, column as i32).into()))
+				.is_some_and(|previous_cell| previous_cell != cell);
+			match changed {
+				true => text += &format!("{}{}{}", HIGHLIGHT, cell, RESET),
+				false => text.push(*cell),
+			}
+		}
+		text += CLEAR_LINE_END;
+		text.push('\n');
+	}
+	text += &format!("{}{}\n{}", status, CLEAR_LINE_END, CLEAR_BELOW);
+	text
+}
+
+// false if the simulation ended before taking all the steps
+fn advance(simulation: &mut dyn Simulation, steps: usize) -> bool {
+	(0..steps).all(|_| simulation.step())
+}
+
+// redraws the simulation in place after every step, until it ends or is quit
+pub fn play(simulation: &mut dyn Simulation, options: &AnimateOptions) -> io::Result<()> {
+	let controls = read_controls();
+	let mut out = io::stdout().lock();
+	write!(out, "{}", CLEAR_SCREEN)?;
+
+	let mut running = advance(simulation, options.skip);
+	let mut paused = options.paused;
+	let mut previous = None;
+
+	loop {
+		let frame = simulation.frame();
+		let state = match (running, paused) {
+			(false, _) => "finished",
+			(true, true) => "paused",
+			(true, false) => "playing",
+		};
+		let status = format!("{} [{}] {}", simulation.status(), state, CONTROLS);
+		write!(out, "{}", draw_frame(&frame, previous.as_ref(), &status))?;
+		out.flush()?;
+		previous = Some(frame);
+
+		if !running {
+			return Ok(());
+		}
+
+		let control = if paused {
+			// without stdin nothing can resume the simulation, so it plays to the end
+			Some(controls.recv().unwrap_or(Control::Play))
+		} else {
+			thread::sleep(options.delay);
+			controls.try_recv().ok()
+		};
+
+		let steps = match control {
+			None => 1,
+			Some(Control::Enter) if paused => 1,
+			Some(Control::Enter) => {
+				paused = true;
+				0
+			}
+			Some(Control::Play) => {
+				paused = false;
+				1
+			}
+			Some(Control::Skip(steps)) => steps,
+			Some(Control::Quit) => return Ok(()),
+		};
+		running = advance(simulation, steps);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn controls() {
+		assert_eq!(parse_control(""), Some(Control::Enter));
+		assert_eq!(parse_control(" 25 "), Some(Control::Skip(25)));
+		assert_eq!(parse_control("q"), Some(Control::Quit));
+		assert_eq!(parse_control("x"), None);
+	}
+
+	#[test]
+	fn highlights_changes() {
+		let previous = Grid::parse_chars("@.\n").unwrap();
+		let frame = Grid::parse_chars(".@\n").unwrap();
+
+		let text = draw_frame(&frame, Some(&previous), "1");
+		let expected = format!("{}{}.{}{}@{}{}\n1{}\n{}", CURSOR_HOME, HIGHLIGHT, RESET, HIGHLIGHT, RESET, CLEAR_LINE_END, CLEAR_LINE_END, CLEAR_BELOW);
+		assert_eq!(text, expected);
+	}
+}
@@ -0,0 +1,92 @@
+use aoc_common::parse::{lines, ParseError};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.tsv";
+
+const HEADER: &str = "# day\tpart\tinput hash\tanswer\n";
+
+// (day, part, input hash)
+type Key = (u8, u8, u64);
+
+// confirmed answers, one per line as "day<TAB>part<TAB>input hash<TAB>answer", the hash being hex
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+	answers: BTreeMap<Key, String>,
+}
+
+impl AnswerStore {
+	// a missing file is an empty store
+	pub fn load(path: &str) -> Result<AnswerStore, String> {
+		match fs::read_to_string(path) {
+			Ok(text) => AnswerStore::parse(&text).map_err(|error| format!("Invalid answers file {}, {}", path, error)),
+			Err(error) if error.kind() == ErrorKind::NotFound => Ok(AnswerStore::default()),
+			Err(error) => Err(format!("Could not read answers file {}: {}", path, error)),
+		}
+	}
+
+	pub fn parse(text: &str) -> Result<AnswerStore, ParseError> {
+		let mut answers = BTreeMap::new();
+
+		for line in lines(text).filter(|line| !line.text.is_empty() && !line.text.starts_with('#')) {
+			let (day, rest) = line.split_once(line.text, "\t", "a tab after the day")?;
+			let (part, rest) = line.split_once(rest, "\t", "a tab after the part")?;
+			let (input_hash, answer) = line.split_once(rest, "\t", "a tab after the input hash")?;
+
+			let day = line.parse(day, "a day number")?;
+			let part = line.parse(part, "a part number")?;
+			let input_hash = u64::from_str_radix(input_hash, 16).map_err(|_| line.error(input_hash, "a hexadecimal input hash"))?;
+
+			answers.insert((day, part, input_hash), answer.to_string());
+		}
+
+		Ok(AnswerStore { answers })
+	}
+
+	pub fn save(&self, path: &str) -> Result<(), String> {
+		fs::write(path, self.to_string()).map_err(|error| format!("Could not write answers file {}: {}", path, error))
+	}
+
+	pub fn get(&self, day: u8, part: u8, input_hash: u64) -> Option<&str> {
+		self.answers.get(&(day, part, input_hash)).map(String::as_str)
+	}
+
+	// returns the answer that was recorded before, if any
+	pub fn insert(&mut self, day: u8, part: u8, input_hash: u64, answer: String) -> Option<String> {
+		self.answers.insert((day, part, input_hash), answer)
+	}
+}
+
+impl std::fmt::Display for AnswerStore {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", HEADER)?;
+		for ((day, part, input_hash), answer) in &self.answers {
+			writeln!(f, "{}\t{}\t{:016x}\t{}", day, part, input_hash, answer)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let mut store = AnswerStore::default();
+		store.insert(16, 2, 0xabc, "45".to_string());
+		store.insert(17, 1, u64::MAX, "4,6,3,5,6,3,5,2,1,0".to_string());
+
+		let parsed = AnswerStore::parse(&store.to_string()).unwrap();
+		assert_eq!(parsed, store);
+		assert_eq!(parsed.get(16, 2, 0xabc), Some("45"));
+		assert_eq!(parsed.get(16, 1, 0xabc), None);
+	}
+
+	#[test]
+	fn invalid_hash() {
+		let error = AnswerStore::parse("1\t1\txyz\t11\n").unwrap_err();
+		assert_eq!((error.line, error.column), (1, 5));
+	}
+}
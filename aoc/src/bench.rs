@@ -0,0 +1,146 @@
+use aoc_common::parse::{lines, ParseError};
+use aoc_common::solution::Day;
+use std::fmt;
+use std::fs;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+	Parse,
+	Part(u8),
+}
+
+impl fmt::Display for Phase {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Phase::Parse => write!(f, "parse"),
+			Phase::Part(part) => write!(f, "part {}", part),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseStats {
+	pub day: u8,
+	pub phase: Phase,
+	pub min: Duration,
+	pub median: Duration,
+	pub max: Duration,
+}
+
+// runs the whole day `runs` times, parsing the input again every time
+pub fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Vec<PhaseStats>, ParseError> {
+	assert!(runs > 0, "Need at least one run");
+	let parts: Vec<u8> = (1..=day.parts).collect();
+
+	// one list of times per phase, parse first
+	let mut times: Vec<Vec<Duration>> = vec![Vec::with_capacity(runs); parts.len() + 1];
+	for _ in 0..runs {
+		let run = day.solve_timed(input, &parts, &[])?;
+		times[0].push(run.parse_time);
+		for (part_times, part_run) in times[1..].iter_mut().zip(run.parts) {
+			part_times.push(part_run.time);
+		}
+	}
+
+	let phases = [Phase::Parse].into_iter().chain(parts.into_iter().map(Phase::Part));
+	Ok(phases.zip(times)
+		.map(|(phase, mut phase_times)| {
+			phase_times.sort();
+			PhaseStats {
+				day: day.day,
+				phase,
+				min: phase_times[0],
+				median: phase_times[phase_times.len() / 2],
+				max: phase_times[phase_times.len() - 1],
+			}
+		})
+		.collect())
+}
+
+// one line per day and phase, as "day<TAB>phase<TAB>min<TAB>median<TAB>max", times in nanoseconds
+pub fn save(stats: &[PhaseStats], path: &str) -> Result<(), String> {
+	let mut text = String::from("# day\tphase\tmin ns\tmedian ns\tmax ns\n");
+	for stat in stats {
+		text += &format!("{}\t{}\t{}\t{}\t{}\n", stat.day, stat.phase, stat.min.as_nanos(), stat.median.as_nanos(), stat.max.as_nanos());
+	}
+
+	fs::write(path, text).map_err(|error| format!("Could not write benchmark results {}: {}", path, error))
+}
+
+pub fn load(path: &str) -> Result<Vec<PhaseStats>, String> {
+	let text = fs::read_to_string(path).map_err(|error| format!("Could not read benchmark results {}: {}", path, error))?;
+	parse(&text).map_err(|error| format!("Invalid benchmark results {}, {}", path, error))
+}
+
+fn parse(text: &str) -> Result<Vec<PhaseStats>, ParseError> {
+	lines(text)
+		.filter(|line| !line.text.is_empty() && !line.text.starts_with('#'))
+		.map(|line| {
+			let fields: Vec<&str> = line.text.split('\t').collect();
+			let [day, phase, min, median, max] = fields[..] else {
+				return Err(line.error(line.text, "5 tab separated fields"));
+			};
+
+			let phase = match phase.strip_prefix("part ") {
+				None if phase == "parse" => Phase::Parse,
+				None => return Err(line.error(phase, "\"parse\" or \"part N\"")),
+				Some(part) => Phase::Part(line.parse(part, "a part number")?),
+			};
+			let nanos = |field| line.parse(field, "a time in nanoseconds").map(Duration::from_nanos);
+
+			Ok(PhaseStats {
+				day: line.parse(day, "a day number")?,
+				phase,
+				min: nanos(min)?,
+				median: nanos(median)?,
+				max: nanos(max)?,
+			})
+		})
+		.collect()
+}
+
+// 3 significant digits in the most readable unit
+pub fn format_duration(duration: Duration) -> String {
+	let nanos = duration.as_nanos() as f64;
+	let (value, unit) = if nanos < 1e3 {
+		(nanos, "ns")
+	} else if nanos < 1e6 {
+		(nanos / 1e3, "µs")
+	} else if nanos < 1e9 {
+		(nanos / 1e6, "ms")
+	} else {
+		(nanos / 1e9, "s")
+	};
+
+	let decimals = if value < 10.0 { 2 } else if value < 100.0 { 1 } else { 0 };
+	format!("{:.*}{}", decimals, value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn save_and_load() {
+		let stats = vec![
+			PhaseStats { day: 6, phase: Phase::Parse, min: Duration::from_nanos(1), median: Duration::from_nanos(2), max: Duration::from_nanos(3) },
+			PhaseStats { day: 6, phase: Phase::Part(2), min: Duration::from_secs(1), median: Duration::from_secs(2), max: Duration::from_secs(3) },
+		];
+
+		let path = std::env::temp_dir().join(format!("aoc_bench_{}.tsv", std::process::id()));
+		let path = path.to_str().unwrap();
+		save(&stats, path).unwrap();
+		let loaded = load(path);
+		fs::remove_file(path).unwrap();
+
+		assert_eq!(loaded.unwrap(), stats);
+	}
+
+	#[test]
+	fn durations() {
+		assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+		assert_eq!(format_duration(Duration::from_micros(1234)), "1.23ms");
+		assert_eq!(format_duration(Duration::from_millis(45_600)), "45.6s");
+	}
+}
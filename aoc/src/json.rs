@@ -0,0 +1,117 @@
+use std::fmt;
+
+// just enough JSON to write reports, objects keep their keys in insertion order
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+	Null,
+	Bool(bool),
+	Int(i128),
+	Float(f64),
+	String(String),
+	Array(Vec<Json>),
+	Object(Vec<(String, Json)>),
+}
+
+impl Json {
+	pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Json {
+		Json::Object(fields.into_iter().map(|(key, value)| (key.into(), value)).collect())
+	}
+}
+
+impl From<&str> for Json {
+	fn from(text: &str) -> Self {
+		Json::String(text.to_string())
+	}
+}
+
+impl From<String> for Json {
+	fn from(text: String) -> Self {
+		Json::String(text)
+	}
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+	fn from(value: Option<T>) -> Self {
+		value.map_or(Json::Null, Into::into)
+	}
+}
+
+macro_rules! impl_json_from_int {
+	($($t:ty),*) => {
+		$(impl From<$t> for Json {
+			fn from(number: $t) -> Self {
+				Json::Int(number as i128)
+			}
+		})*
+	};
+}
+
+impl_json_from_int!(u8, u32, u64, u128, usize, i32, i64, i128);
+
+fn write_string(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+	write!(f, "\"")?;
+	for c in text.chars() {
+		match c {
+			'"' => write!(f, "\\\"")?,
+			'\\' => write!(f, "\\\\")?,
+			'\n' => write!(f, "\\n")?,
+			'\r' => write!(f, "\\r")?,
+			'\t' => write!(f, "\\t")?,
+			c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+			c => write!(f, "{}", c)?,
+		}
+	}
+	write!(f, "\"")
+}
+
+// compact, on a single line
+impl fmt::Display for Json {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Json::Null => write!(f, "null"),
+			Json::Bool(value) => write!(f, "{}", value),
+			Json::Int(number) => write!(f, "{}", number),
+			Json::Float(number) if number.is_finite() => write!(f, "{}", number),
+			Json::Float(_) => write!(f, "null"),
+			Json::String(text) => write_string(f, text),
+			Json::Array(values) => {
+				write!(f, "[")?;
+				for (i, value) in values.iter().enumerate() {
+					if i > 0 {
+						write!(f, ",")?;
+					}
+					write!(f, "{}", value)?;
+				}
+				write!(f, "]")
+			}
+			Json::Object(fields) => {
+				write!(f, "{{")?;
+				for (i, (key, value)) in fields.iter().enumerate() {
+					if i > 0 {
+						write!(f, ",")?;
+					}
+					write_string(f, key)?;
+					write!(f, ":{}", value)?;
+				}
+				write!(f, "}}")
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn nested() {
+		let json = Json::object([
+			("day", Json::from(17u8)),
+			("answer", Json::from("4,6\n\"3\"")),
+			("parts", Json::Array(vec![Json::Int(-1), Json::Float(0.5), Json::Null, Json::Bool(true)])),
+			("stats", Json::object::<&str>([])),
+		]);
+
+		assert_eq!(json.to_string(), r#"{"day":17,"answer":"4,6\n\"3\"","parts":[-1,0.5,null,true],"stats":{}}"#);
+	}
+}
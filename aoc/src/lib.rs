@@ -0,0 +1,7 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod json;
+pub mod pool;
+pub mod registry;
+pub mod report;
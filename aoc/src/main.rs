@@ -0,0 +1,841 @@
+use aoc::animate::{play, AnimateOptions};
+use aoc::answers::{AnswerStore, DEFAULT_ANSWERS_PATH};
+use aoc::bench::{self, bench_day, format_duration, PhaseStats};
+use aoc::json::Json;
+use aoc::pool::run_ordered;
+use aoc::registry::{get_day, DAYS};
+use aoc::report::run_json;
+use aoc_common::input::{default_input_path, hash_input, input_file_name, InputSource, INPUTS_DIR};
+use aoc_common::log;
+use aoc_common::solution::{Answer, Day};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = concat!(
+	"Usage, with -q, -v or -vv anywhere to log less or more on stderr:\n",
+	"  aoc list\n",
+	"  aoc run --day <N> [--part <1|2>] [--input <path|->] [--record] [--answers <path>] [--format <text|json>]\n",
+	"  aoc run --day <N> [--part <1|2>] [--input <path|-> | --example <N>] [--param <name=value>]... [--format <text|json>]\n",
+	"  aoc run --day <N> [--part <1|2>] [--input <path|-> | --example <N>] [--param <name=value>]... --animate [--delay <ms>] [--skip <N>] [--paused]\n",
+	"  aoc run --all [--part <1|2>] [--inputs <dir> | --example <N>] [--jobs <N>] [--format <text|json>]\n",
+	"  aoc run (--day <N> [--input <path|->] | --all [--inputs <dir>]) --check\n",
+	"  aoc verify [--inputs <dir>] [--answers <path>] [--record]\n",
+	"  aoc bench [--day <N> [--example <N>]] [--runs <N>] [--inputs <dir>] [--save <path>] [--compare <path>]\n",
+	"  aoc generate --day <N> [--size <N>] [--seed <N>]\n",
+	"  aoc diff [--day <N> [--part <1|2>]] [--size <N>] [--seeds <N>]\n",
+	"  aoc render --day <N> [--input <path|-> | --example <N>] [--param <name=value>]... [--out <dir>] [--scale <N>] [--grey]\n",
+	"\n",
+	"--input - reads the input from stdin. Without --input or --inputs, the input of day N is\n",
+	"$AOC_INPUTS/day_NN.txt if AOC_INPUTS is set, otherwise inputs/day_NN.txt in the current\n",
+	"directory or the closest parent directory that has it.\n",
+	"Answers are recorded in answers.tsv by default.\n",
+	"--param sets one of the day's puzzle constants, which list shows with their defaults.\n",
+	"--format json prints one JSON object per line, with the answers, timings and solver stats.\n",
+	"--animate redraws the steps of the days that are simulations in the terminal, and reads controls\n",
+	"from stdin: Enter pauses or takes a step, N skips N steps, p plays and q quits.\n",
+	"--check validates the input instead of solving it, and lists every problem found rather than the first.\n",
+	"generate prints a random input for day N, the same one for the same size and seed. The size is the\n",
+	"day's main dimension, like its number of lines or the side of its map.\n",
+	"diff checks the parts that have a reference implementation against it, on generated inputs of every\n",
+	"size up to --size, and prints the smallest input it finds on which they disagree.\n",
+	"render writes the pictures a day draws of its state to <dir>/day_NN_<name>.ppm (.pgm with --grey),\n",
+	"in renders/ by default.\n",
+);
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+const DEFAULT_ANIMATE_DELAY_MS: u64 = 100;
+const DEFAULT_GENERATE_SIZE: usize = 100;
+const DEFAULT_DIFF_SIZE: usize = 20;
+const DEFAULT_DIFF_SEEDS: u64 = 50;
+const DEFAULT_RENDER_DIR: &str = "renders";
+// pixels per cell
+const DEFAULT_RENDER_SCALE: usize = 4;
+
+#[derive(PartialEq)]
+enum Format {
+	Text,
+	Json,
+}
+
+impl std::str::FromStr for Format {
+	type Err = ();
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		match text {
+			"text" => Ok(Format::Text),
+			"json" => Ok(Format::Json),
+			_ => Err(()),
+		}
+	}
+}
+
+enum InputArg {
+	Default,
+	// a file path or "-" for stdin
+	Path(String),
+	Example(usize),
+}
+
+struct RunArgs {
+	// None for --all
+	day: Option<u8>,
+	part: Option<u8>,
+	input: InputArg,
+	record: bool,
+	answers_path: String,
+	format: Format,
+	// only for --all
+	inputs_dir: Option<String>,
+	jobs: usize,
+	animate: Option<AnimateOptions>,
+	check: bool,
+	// --param overrides, only with --day
+	params: Vec<(String, String)>,
+}
+
+struct VerifyArgs {
+	inputs_dir: Option<String>,
+	answers_path: String,
+	record: bool,
+}
+
+struct BenchArgs {
+	day: Option<u8>,
+	example: Option<usize>,
+	runs: usize,
+	inputs_dir: Option<String>,
+	save_path: Option<String>,
+	compare_path: Option<String>,
+}
+
+struct GenerateArgs {
+	day: u8,
+	size: usize,
+	seed: u64,
+}
+
+struct DiffArgs {
+	day: Option<u8>,
+	part: Option<u8>,
+	max_size: usize,
+	seeds: u64,
+}
+
+struct RenderArgs {
+	day: u8,
+	input: InputArg,
+	out_dir: String,
+	scale: usize,
+	grey: bool,
+	params: Vec<(String, String)>,
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+	let value = value.ok_or(format!("Missing value for {}", flag))?;
+	value.parse().map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_param(value: Option<&String>) -> Result<(String, String), String> {
+	let value = value.ok_or("Missing value for --param")?;
+	let (name, value) = value.split_once('=').ok_or(format!("Invalid value for --param: {}, expected name=value", value))?;
+	Ok((name.to_string(), value.to_string()))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+	let mut day = None;
+	let mut all = false;
+	let mut part = None;
+	let mut input = None;
+	let mut record = false;
+	let mut answers_path = DEFAULT_ANSWERS_PATH.to_string();
+	let mut format = Format::Text;
+	let mut inputs_dir = None;
+	let mut jobs = None;
+	let mut animate = false;
+	let mut delay_ms = None;
+	let mut skip = None;
+	let mut paused = false;
+	let mut check = false;
+	let mut params = Vec::new();
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--day" => day = Some(parse_value(arg, args_iter.next())?),
+			"--all" => all = true,
+			"--inputs" => inputs_dir = Some(parse_value(arg, args_iter.next())?),
+			"--jobs" => jobs = Some(parse_value(arg, args_iter.next())?),
+			"--part" => part = Some(parse_value(arg, args_iter.next())?),
+			"--input" => input = Some(InputArg::Path(parse_value(arg, args_iter.next())?)),
+			"--example" => input = Some(InputArg::Example(parse_value(arg, args_iter.next())?)),
+			"--record" => record = true,
+			"--answers" => answers_path = parse_value(arg, args_iter.next())?,
+			"--format" => format = parse_value(arg, args_iter.next())?,
+			"--animate" => animate = true,
+			"--delay" => delay_ms = Some(parse_value(arg, args_iter.next())?),
+			"--skip" => skip = Some(parse_value(arg, args_iter.next())?),
+			"--paused" => paused = true,
+			"--check" => check = true,
+			"--param" => params.push(parse_param(args_iter.next())?),
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	if let Some(part) = part && part != 1 && part != 2 {
+		return Err(format!("Invalid part: {}", part));
+	}
+
+	let animate = match animate {
+		true if all || record || format == Format::Json => {
+			return Err("--animate can't be used with --all, --record or --format json".to_string());
+		}
+		true => Some(AnimateOptions {
+			delay: Duration::from_millis(delay_ms.unwrap_or(DEFAULT_ANIMATE_DELAY_MS)),
+			skip: skip.unwrap_or(0),
+			paused,
+		}),
+		false if delay_ms.is_some() || skip.is_some() || paused => {
+			return Err("--delay, --skip and --paused need --animate".to_string());
+		}
+		false => None,
+	};
+
+	if check && (part.is_some() || record || format == Format::Json || animate.is_some() || !params.is_empty()) {
+		return Err("--check can't be used with --part, --record, --format json, --animate or --param".to_string());
+	}
+	// the recorded answers are the puzzle's
+	if record && !params.is_empty() {
+		return Err("--record can't be used with --param".to_string());
+	}
+
+	let input = input.unwrap_or(InputArg::Default);
+	if record && matches!(input, InputArg::Example(_)) {
+		return Err("Only answers for input files can be recorded".to_string());
+	}
+
+	if all {
+		if day.is_some() {
+			return Err("--all and --day can't be used together".to_string());
+		}
+		if record {
+			return Err("--record needs --day".to_string());
+		}
+		if matches!(input, InputArg::Path(_)) {
+			return Err("--all reads every day's input, use --inputs <dir> instead of --input".to_string());
+		}
+		if !params.is_empty() {
+			return Err("--param needs --day".to_string());
+		}
+	} else {
+		if day.is_none() {
+			return Err("Missing --day or --all".to_string());
+		}
+		if inputs_dir.is_some() || jobs.is_some() {
+			return Err("--inputs and --jobs need --all".to_string());
+		}
+	}
+
+	let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(4, |n| n.get()));
+	if jobs == 0 {
+		return Err("--jobs must be at least 1".to_string());
+	}
+
+	Ok(RunArgs {
+		day,
+		part,
+		input,
+		record,
+		answers_path,
+		format,
+		inputs_dir,
+		jobs,
+		animate,
+		check,
+		params,
+	})
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+	let mut verify_args = VerifyArgs {
+		inputs_dir: None,
+		answers_path: DEFAULT_ANSWERS_PATH.to_string(),
+		record: false,
+	};
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--inputs" => verify_args.inputs_dir = Some(parse_value(arg, args_iter.next())?),
+			"--answers" => verify_args.answers_path = parse_value(arg, args_iter.next())?,
+			"--record" => verify_args.record = true,
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	Ok(verify_args)
+}
+
+fn parse_generate_args(args: &[String]) -> Result<GenerateArgs, String> {
+	let mut day = None;
+	let mut size = DEFAULT_GENERATE_SIZE;
+	let mut seed = 0;
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--day" => day = Some(parse_value(arg, args_iter.next())?),
+			"--size" => size = parse_value(arg, args_iter.next())?,
+			"--seed" => seed = parse_value(arg, args_iter.next())?,
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	Ok(GenerateArgs {
+		day: day.ok_or("Missing --day")?,
+		size,
+		seed,
+	})
+}
+
+fn parse_diff_args(args: &[String]) -> Result<DiffArgs, String> {
+	let mut diff_args = DiffArgs {
+		day: None,
+		part: None,
+		max_size: DEFAULT_DIFF_SIZE,
+		seeds: DEFAULT_DIFF_SEEDS,
+	};
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--day" => diff_args.day = Some(parse_value(arg, args_iter.next())?),
+			"--part" => diff_args.part = Some(parse_value(arg, args_iter.next())?),
+			"--size" => diff_args.max_size = parse_value(arg, args_iter.next())?,
+			"--seeds" => diff_args.seeds = parse_value(arg, args_iter.next())?,
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	if diff_args.part.is_some() && diff_args.day.is_none() {
+		return Err("--part needs --day".to_string());
+	}
+
+	Ok(diff_args)
+}
+
+fn parse_render_args(args: &[String]) -> Result<RenderArgs, String> {
+	let mut day = None;
+	let mut input = InputArg::Default;
+	let mut out_dir = DEFAULT_RENDER_DIR.to_string();
+	let mut scale = DEFAULT_RENDER_SCALE;
+	let mut grey = false;
+	let mut params = Vec::new();
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--day" => day = Some(parse_value(arg, args_iter.next())?),
+			"--input" => input = InputArg::Path(parse_value(arg, args_iter.next())?),
+			"--example" => input = InputArg::Example(parse_value(arg, args_iter.next())?),
+			"--out" => out_dir = parse_value(arg, args_iter.next())?,
+			"--scale" => scale = parse_value(arg, args_iter.next())?,
+			"--grey" => grey = true,
+			"--param" => params.push(parse_param(args_iter.next())?),
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	if scale == 0 {
+		return Err("--scale must be at least 1".to_string());
+	}
+
+	Ok(RenderArgs {
+		day: day.ok_or("Missing --day")?,
+		input,
+		out_dir,
+		scale,
+		grey,
+		params,
+	})
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+	let mut bench_args = BenchArgs {
+		day: None,
+		example: None,
+		runs: DEFAULT_BENCH_RUNS,
+		inputs_dir: None,
+		save_path: None,
+		compare_path: None,
+	};
+
+	let mut args_iter = args.iter();
+	while let Some(arg) = args_iter.next() {
+		match arg.as_str() {
+			"--day" => bench_args.day = Some(parse_value(arg, args_iter.next())?),
+			"--example" => bench_args.example = Some(parse_value(arg, args_iter.next())?),
+			"--runs" => bench_args.runs = parse_value(arg, args_iter.next())?,
+			"--inputs" => bench_args.inputs_dir = Some(parse_value(arg, args_iter.next())?),
+			"--save" => bench_args.save_path = Some(parse_value(arg, args_iter.next())?),
+			"--compare" => bench_args.compare_path = Some(parse_value(arg, args_iter.next())?),
+			_ => return Err(format!("Unknown argument: {}", arg))
+		}
+	}
+
+	if bench_args.runs == 0 {
+		return Err("--runs must be at least 1".to_string());
+	}
+	if bench_args.example.is_some() && bench_args.day.is_none() {
+		return Err("--example needs --day".to_string());
+	}
+
+	Ok(bench_args)
+}
+
+// the day's file in inputs_dir if there is one, otherwise the default input of the day,
+// with the path it was read from. Ok(None) if the file does not exist
+fn load_day_input(inputs_dir: Option<&str>, day: u8) -> Result<Option<(String, String)>, String> {
+	let source = match inputs_dir {
+		Some(dir) => Ok(InputSource::File(Path::new(dir).join(input_file_name(day)))),
+		None => default_input_path(day).map(InputSource::File),
+	};
+
+	match source.and_then(|source| Ok((source.read()?, source.to_string()))) {
+		Ok(input) => Ok(Some(input)),
+		Err(error) if error.is_missing() => Ok(None),
+		Err(error) => Err(error.to_string()),
+	}
+}
+
+fn print_missing_input(inputs_dir: Option<&str>, day: u8) {
+	println!("Day {}: missing input {}/{}", day, inputs_dir.unwrap_or(INPUTS_DIR), input_file_name(day));
+}
+
+// the input and where it was read from
+fn read_input(day: &Day, input: &InputArg) -> Result<(String, String), String> {
+	match input {
+		InputArg::Example(n) => {
+			let input = (day.example)(*n).ok_or(format!("Day {} has no example {}", day.day, n))?;
+			Ok((input.to_string(), format!("example {}", n)))
+		}
+		input_arg => {
+			let source = match input_arg {
+				InputArg::Path(path) => InputSource::from_arg(path),
+				_ => InputSource::File(default_input_path(day.day).map_err(|error| error.to_string())?),
+			};
+			Ok((source.read().map_err(|error| error.to_string())?, source.to_string()))
+		}
+	}
+}
+
+// prints every problem in the input, sorted by position, false if there are any
+fn print_check(day: &Day, input: &str, source: &str) -> bool {
+	let mut problems = day.check(input);
+	problems.sort_by_key(|problem| (problem.line, problem.column));
+
+	match problems.len() {
+		0 => println!("Day {}: {} is valid", day.day, source),
+		1 => println!("Day {}: {} has 1 problem", day.day, source),
+		n => println!("Day {}: {} has {} problems", day.day, source, n),
+	}
+	for problem in &problems {
+		println!("  {}", problem);
+	}
+
+	problems.is_empty()
+}
+
+// answers that span several lines, like the matrices of day 1, start on the line after the part
+fn print_answer(day: u8, part: u8, answer: &Answer) {
+	let answer = answer.to_string();
+	match answer.contains('\n') {
+		true => println!("Day {} part {}:\n{}", day, part, answer),
+		false => println!("Day {} part {}: {}", day, part, answer),
+	}
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+	let Some(day) = args.day else {
+		return run_all(args);
+	};
+	let day = get_day(day).ok_or(format!("Day {} is not implemented", day))?;
+
+	let parts = match args.part {
+		Some(part) if day.has_part(part) => vec![part],
+		Some(part) => return Err(format!("Day {} part {} is not implemented", day.day, part)),
+		None => (1..=day.parts).collect(),
+	};
+
+	day.check_params(&args.params).map_err(|error| format!("Day {}: {}", day.day, error))?;
+	let (input, source) = read_input(day, &args.input)?;
+	log::debug!("Day {} input read from {}, {} bytes", day.day, source, input.len());
+	for (name, value) in &args.params {
+		log::debug!("Day {} parameter {} = {}", day.day, name, value);
+	}
+
+	if args.check {
+		return match print_check(day, &input, &source) {
+			true => Ok(()),
+			false => Err(format!("Day {} input is invalid", day.day)),
+		};
+	}
+
+	if let Some(options) = &args.animate {
+		// the first part when there's no --part
+		let part = parts[0];
+		let mut simulation = day.simulation(&input, part, &args.params)
+			.map_err(|error| format!("Day {} input is invalid, {}", day.day, error))?
+			.ok_or(format!("Day {} is not a simulation", day.day))?;
+		return play(simulation.as_mut(), options).map_err(|error| format!("Could not draw the animation: {}", error));
+	}
+
+	let run = day.solve_timed(&input, &parts, &args.params)
+		.map_err(|error| format!("Day {} input is invalid, {}", day.day, error))?;
+
+	match args.format {
+		Format::Text => {
+			for part_run in &run.parts {
+				print_answer(day.day, part_run.part, &part_run.answer);
+			}
+		}
+		Format::Json => println!("{}", run_json(day.day, &source, &input, &run)),
+	}
+
+	let num_failed = run.parts.iter().filter(|part_run| part_run.answer.is_failed()).count();
+	if num_failed > 0 {
+		return Err(format!("Day {}: {} of {} parts have no answer", day.day, num_failed, run.parts.len()));
+	}
+
+	if args.record {
+		// keep stdout a valid JSON stream
+		let notify = |message: String| match args.format {
+			Format::Text => println!("{}", message),
+			Format::Json => eprintln!("{}", message),
+		};
+
+		// one answer per line in the store
+		if let Some(part_run) = run.parts.iter().find(|part_run| part_run.answer.to_string().contains('\n')) {
+			return Err(format!("Day {} part {} has an answer on several lines, which can't be recorded", day.day, part_run.part));
+		}
+
+		let mut store = AnswerStore::load(&args.answers_path)?;
+		let input_hash = hash_input(&input);
+
+		for part_run in run.parts {
+			let answer = part_run.answer.to_string();
+			match store.insert(day.day, part_run.part, input_hash, answer.clone()) {
+				Some(previous) if previous != answer => notify(format!("Day {} part {}: replaced recorded answer {}", day.day, part_run.part, previous)),
+				_ => {}
+			}
+		}
+
+		store.save(&args.answers_path)?;
+		notify(format!("Recorded in {}", args.answers_path));
+	}
+
+	Ok(())
+}
+
+// runs every part of every day on a pool of threads, printing the results in day order
+fn run_all(args: RunArgs) -> Result<(), String> {
+	let print_json = args.format == Format::Json;
+
+	// read every input first, the workers only solve
+	let mut inputs = Vec::new();
+	for day in DAYS {
+		let input = match args.input {
+			InputArg::Example(n) => (day.example)(n).map(|input| (input.to_string(), format!("example {}", n))),
+			_ => load_day_input(args.inputs_dir.as_deref(), day.day)?,
+		};
+
+		match input {
+			Some((input, source)) => inputs.push((day, input, source)),
+			None if matches!(args.input, InputArg::Example(_)) => {}
+			None if print_json => println!("{}", Json::object([
+				("day", Json::from(day.day)),
+				("error", Json::from(format!("missing input {}/{}", args.inputs_dir.as_deref().unwrap_or(INPUTS_DIR), input_file_name(day.day)))),
+			])),
+			None => print_missing_input(args.inputs_dir.as_deref(), day.day),
+		}
+	}
+
+	if args.check {
+		let num_invalid = inputs.iter()
+			.filter(|(day, input, source)| !print_check(day, input, source))
+			.count();
+		if num_invalid > 0 {
+			return Err(format!("{} of {} inputs are invalid", num_invalid, inputs.len()));
+		}
+		return Ok(());
+	}
+
+	let jobs: Vec<_> = inputs.iter()
+		.flat_map(|(day, input, source)| {
+			(1..=day.parts)
+				.filter(|part| args.part.is_none_or(|only_part| only_part == *part))
+				.map(move |part| (*day, input.as_str(), source.as_str(), part))
+		})
+		.collect();
+
+	log::debug!("Running {} parts of {} days on {} threads", jobs.len(), inputs.len(), args.jobs);
+	let results = run_ordered(jobs.clone(), args.jobs, |(day, input, _source, part)| day.solve_timed(input, &[part], &[]));
+
+	let num_jobs = jobs.len();
+	let mut failures = 0;
+	for ((day, input, source, part), result) in jobs.into_iter().zip(results) {
+		let error = match result {
+			Ok(Ok(run)) if run.parts[0].answer.is_failed() => run.parts[0].answer.to_string(),
+			Ok(Ok(run)) => {
+				match args.format {
+					Format::Text => print_answer(day.day, part, &run.parts[0].answer),
+					Format::Json => println!("{}", run_json(day.day, source, input, &run)),
+				}
+				continue;
+			}
+			Ok(Err(error)) => format!("input is invalid, {}", error),
+			Err(message) => format!("panicked, {}", message),
+		};
+
+		failures += 1;
+		match args.format {
+			Format::Text => println!("Day {} part {}: {}", day.day, part, error),
+			Format::Json => println!("{}", Json::object([
+				("day", Json::from(day.day)),
+				("part", Json::from(part)),
+				("error", Json::from(error)),
+			])),
+		}
+	}
+
+	if failures > 0 {
+		return Err(format!("{} of {} parts failed", failures, num_jobs));
+	}
+
+	Ok(())
+}
+
+// re-runs every day on its input and compares the answers to the recorded ones
+fn verify(args: VerifyArgs) -> Result<(), String> {
+	let mut store = AnswerStore::load(&args.answers_path)?;
+	let (mut num_ok, mut num_new, mut num_missing, mut failures) = (0, 0, 0, 0);
+
+	for day in DAYS {
+		let Some((input, _source)) = load_day_input(args.inputs_dir.as_deref(), day.day)? else {
+			print_missing_input(args.inputs_dir.as_deref(), day.day);
+			num_missing += 1;
+			continue;
+		};
+
+		let parts: Vec<u8> = (1..=day.parts).collect();
+		let answers = match day.solve(&input, &parts) {
+			Ok(answers) => answers,
+			Err(error) => {
+				println!("Day {}: input is invalid, {}", day.day, error);
+				failures += 1;
+				continue;
+			}
+		};
+
+		let input_hash = hash_input(&input);
+		for (part, answer) in parts.into_iter().zip(answers) {
+			if answer.is_failed() {
+				println!("Day {} part {}: {}", day.day, part, answer);
+				failures += 1;
+				continue;
+			}
+
+			let answer = answer.to_string();
+			match store.get(day.day, part, input_hash) {
+				Some(recorded) if recorded == answer => {
+					println!("Day {} part {}: ok", day.day, part);
+					num_ok += 1;
+				}
+				Some(recorded) => {
+					println!("Day {} part {}: MISMATCH, recorded {}, got {}", day.day, part, recorded, answer);
+					failures += 1;
+				}
+				None => {
+					println!("Day {} part {}: new answer {}", day.day, part, answer);
+					num_new += 1;
+					if args.record {
+						store.insert(day.day, part, input_hash, answer);
+					}
+				}
+			}
+		}
+	}
+
+	if args.record && num_new > 0 {
+		store.save(&args.answers_path)?;
+		println!("Recorded {} new answers in {}", num_new, args.answers_path);
+	}
+
+	println!("{} ok, {} failed, {} new, {} missing inputs", num_ok, failures, num_new, num_missing);
+	if failures > 0 {
+		return Err(format!("{} answers or inputs failed verification", failures));
+	}
+
+	Ok(())
+}
+
+// times every phase of the selected days, optionally against the results of an earlier bench
+fn bench(args: BenchArgs) -> Result<(), String> {
+	let baseline = args.compare_path.as_deref().map(bench::load).transpose()?;
+
+	let days: Vec<_> = match args.day {
+		Some(day) => vec![get_day(day).ok_or(format!("Day {} is not implemented", day))?],
+		None => DAYS.iter().collect(),
+	};
+
+	let mut all_stats = Vec::new();
+	for day in days {
+		let input = match args.example {
+			Some(n) => (day.example)(n).ok_or(format!("Day {} has no example {}", day.day, n))?.to_string(),
+			None => match load_day_input(args.inputs_dir.as_deref(), day.day)? {
+				Some((input, _source)) => input,
+				None => {
+					print_missing_input(args.inputs_dir.as_deref(), day.day);
+					continue;
+				}
+			},
+		};
+
+		let stats = bench_day(day, &input, args.runs)
+			.map_err(|error| format!("Day {} input is invalid, {}", day.day, error))?;
+
+		for stat in &stats {
+			let previous = baseline.iter()
+				.flatten()
+				.find(|previous| previous.day == stat.day && previous.phase == stat.phase);
+			println!("{}", format_stats(stat, previous));
+		}
+		all_stats.extend(stats);
+	}
+
+	if let Some(path) = &args.save_path {
+		bench::save(&all_stats, path)?;
+		println!("Saved in {}", path);
+	}
+
+	Ok(())
+}
+
+fn format_stats(stat: &PhaseStats, previous: Option<&PhaseStats>) -> String {
+	let mut line = format!(
+		"Day {} {}: min {}, median {}, max {}",
+		stat.day, stat.phase, format_duration(stat.min), format_duration(stat.median), format_duration(stat.max)
+	);
+
+	if let Some(previous) = previous {
+		let change = (stat.median.as_secs_f64() / previous.median.as_secs_f64() - 1.0) * 100.0;
+		line += &format!(" ({:+.1}% against a median of {})", change, format_duration(previous.median));
+	}
+
+	line
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+	let day = get_day(args.day).ok_or(format!("Day {} is not implemented", args.day))?;
+	let input = day.generate(args.size, args.seed).ok_or(format!("Day {} has no input generator", day.day))?;
+	print!("{}", input);
+	Ok(())
+}
+
+fn diff(args: DiffArgs) -> Result<(), String> {
+	let days: Vec<&Day> = match args.day {
+		Some(day) => vec![get_day(day).ok_or(format!("Day {} is not implemented", day))?],
+		None => DAYS.iter().filter(|day| !day.reference_parts.is_empty()).collect(),
+	};
+
+	let mut num_divergences = 0;
+	for day in days {
+		let parts = match args.part {
+			Some(part) => vec![part],
+			None => day.reference_parts.to_vec(),
+		};
+		if parts.is_empty() {
+			return Err(format!("Day {} has no reference implementation", day.day));
+		}
+
+		for part in parts {
+			match day.find_divergence(part, args.max_size, args.seeds)? {
+				None => println!("Day {} part {}: same answers on {} generated inputs", day.day, part, args.max_size as u64 * args.seeds),
+				Some(divergence) => {
+					num_divergences += 1;
+					print!("{}", divergence);
+				}
+			}
+		}
+	}
+
+	if num_divergences > 0 {
+		return Err(format!("{} parts disagree with their reference", num_divergences));
+	}
+
+	Ok(())
+}
+
+fn render(args: RenderArgs) -> Result<(), String> {
+	let day = get_day(args.day).ok_or(format!("Day {} is not implemented", args.day))?;
+	day.check_params(&args.params).map_err(|error| format!("Day {}: {}", day.day, error))?;
+	let (input, _source) = read_input(day, &args.input)?;
+
+	let images = day.render(&input, &args.params)
+		.map_err(|error| format!("Day {} input is invalid, {}", day.day, error))?;
+	if images.is_empty() {
+		return Err(format!("Day {} does not draw anything", day.day));
+	}
+
+	fs::create_dir_all(&args.out_dir)
+		.map_err(|error| format!("Could not create {}: {}", args.out_dir, error))?;
+	let extension = if args.grey { "pgm" } else { "ppm" };
+	for (name, image) in images {
+		let path = Path::new(&args.out_dir).join(format!("day_{:02}_{}.{}", day.day, name, extension));
+		image.with_scale(args.scale).save(&path)
+			.map_err(|error| format!("Could not write {}: {}", path.display(), error))?;
+		println!("{}", path.display());
+	}
+
+	Ok(())
+}
+
+fn list() -> Result<(), String> {
+	for day in DAYS {
+		let parts: Vec<String> = (1..=day.parts).map(|part| part.to_string()).collect();
+		println!("Day {} parts: {}", day.day, parts.join(", "));
+		for (name, description) in day.params {
+			match day.param_default(name) {
+				Some(default) => println!("  --param {}={}: {}", name, default, description),
+				None => println!("  --param {} (not set): {}", name, description),
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn main() -> ExitCode {
+	let args = log::apply_verbosity_flags(env::args().skip(1));
+
+	let result = match args.first().map(String::as_str) {
+		Some("run") => parse_run_args(&args[1..]).and_then(run),
+		Some("list") => list(),
+		Some("verify") => parse_verify_args(&args[1..]).and_then(verify),
+		Some("bench") => parse_bench_args(&args[1..]).and_then(bench),
+		Some("generate") => parse_generate_args(&args[1..]).and_then(generate),
+		Some("diff") => parse_diff_args(&args[1..]).and_then(diff),
+		Some("render") => parse_render_args(&args[1..]).and_then(render),
+		_ => Err(USAGE.to_string()),
+	};
+
+	if let Err(message) = result {
+		eprintln!("{}", message);
+		return ExitCode::FAILURE;
+	}
+
+	ExitCode::SUCCESS
+}
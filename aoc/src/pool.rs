@@ -0,0 +1,63 @@
+use aoc_common::diff::panic_message;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// Runs `job` on every item using at most `workers` threads, and returns the results in the order of
+// the items. A job that panics gives Err with the panic message, the other jobs carry on.
+pub fn run_ordered<T, R, F>(items: Vec<T>, workers: usize, job: F) -> Vec<Result<R, String>>
+where
+	T: Send,
+	R: Send,
+	F: Fn(T) -> R + Sync,
+{
+	let num_items = items.len();
+	let items: Vec<Mutex<Option<T>>> = items.into_iter().map(|item| Mutex::new(Some(item))).collect();
+	let results: Vec<Mutex<Option<Result<R, String>>>> = (0..num_items).map(|_| Mutex::new(None)).collect();
+	let next_item = AtomicUsize::new(0);
+
+	// the panics are reported through the results, don't also print them
+	let previous_hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+
+	thread::scope(|scope| {
+		for _ in 0..workers.clamp(1, num_items.max(1)) {
+			scope.spawn(|| loop {
+				let i = next_item.fetch_add(1, Ordering::Relaxed);
+				if i >= num_items {
+					break;
+				}
+
+				let item = items[i].lock().unwrap().take().expect("Every item is taken once");
+				let result = panic::catch_unwind(AssertUnwindSafe(|| job(item))).map_err(panic_message);
+				*results[i].lock().unwrap() = Some(result);
+			});
+		}
+	});
+
+	panic::set_hook(previous_hook);
+
+	results.into_iter()
+		.map(|result| result.into_inner().unwrap().expect("Every item has a result"))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn keeps_order_and_survives_panics() {
+		let results = run_ordered((0..20).collect(), 4, |i: u32| {
+			if i == 7 {
+				panic!("seven");
+			}
+			i * 2
+		});
+
+		assert_eq!(results.len(), 20);
+		assert_eq!(results[7], Err("seven".to_string()));
+		assert!(results.iter().enumerate().filter(|(i, _)| *i != 7).all(|(i, result)| *result == Ok(i as u32 * 2)));
+	}
+}
@@ -0,0 +1,69 @@
+use aoc_common::solution::Day;
+
+// every implemented day, in day order
+pub const DAYS: &[Day] = &[
+	Day::of::<day_1::Day1>(),
+	Day::of::<day_2::Day2>(),
+	Day::of::<day_3::Day3>(),
+	Day::of::<day_4::Day4>(),
+	Day::of::<day_5::Day5>(),
+	Day::of::<day_6::Day6>(),
+	Day::of::<day_7::Day7>(),
+	Day::of::<day_8::Day8>(),
+	Day::of::<day_9::Day9>(),
+	Day::of::<day_10::Day10>(),
+	Day::of::<day_11::Day11>(),
+	Day::of::<day_12::Day12>(),
+	Day::of::<day_13::Day13>(),
+	Day::of::<day_14::Day14>(),
+	Day::of::<day_15::Day15>(),
+	Day::of::<day_16::Day16>(),
+	Day::of::<day_17::Day17>(),
+];
+
+pub fn get_day(day: u8) -> Option<&'static Day> {
+	DAYS.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn generated_inputs_are_solvable() {
+		for day in DAYS {
+			let input = day.generate(12, 1).unwrap_or_else(|| panic!("Day {} has no generator", day.day));
+			assert_eq!(day.generate(12, 1).as_ref(), Some(&input), "Day {} generator is not deterministic", day.day);
+
+			let parts: Vec<u8> = (1..=day.parts).collect();
+			if let Err(error) = day.solve(&input, &parts) {
+				panic!("Day {} generated an invalid input, {}", day.day, error);
+			}
+			assert_eq!(day.check(&input), vec![], "Day {} check disagrees with parse", day.day);
+		}
+	}
+
+	#[test]
+	fn examples_pass_the_check() {
+		for day in DAYS {
+			let examples = (1..).map_while(|n| (day.example)(n));
+			for (i, example) in examples.enumerate() {
+				assert_eq!(day.check(example), vec![], "Day {} example {}", day.day, i + 1);
+			}
+		}
+	}
+
+	#[test]
+	fn param_defaults_can_be_set_back() {
+		for day in DAYS {
+			for (name, _description) in day.params {
+				if let Some(default) = day.param_default(name) {
+					let overrides = [(name.to_string(), default)];
+					assert_eq!(day.check_params(&overrides), Ok(()), "Day {} parameter {}", day.day, name);
+				}
+			}
+		}
+		assert_eq!(get_day(16).unwrap().param_default("start_facing").as_deref(), Some("E"));
+		assert_eq!(get_day(1).unwrap().param_default("reference"), None);
+	}
+}
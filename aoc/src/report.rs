@@ -0,0 +1,50 @@
+use crate::json::Json;
+use aoc_common::input::hash_input;
+use aoc_common::solution::{Answer, PartRun, TimedRun};
+use aoc_common::stats::Stat;
+
+pub fn answer_json(answer: &Answer) -> Json {
+	match answer {
+		Answer::Number(number) => Json::Int(*number),
+		Answer::Text(text) => Json::from(text.as_str()),
+		// see part_json
+		Answer::Failed(_) => Json::Null,
+	}
+}
+
+fn stats_json(stats: &[Stat]) -> Json {
+	Json::object(stats.iter().map(|stat| (stat.name, Json::from(stat.value))))
+}
+
+// a failed part has a null answer and the reason in "error"
+fn part_json(part_run: &PartRun) -> Json {
+	let mut fields = vec![
+		("part", Json::from(part_run.part)),
+		("answer", answer_json(&part_run.answer)),
+	];
+	if let Answer::Failed(reason) = &part_run.answer {
+		fields.push(("error", Json::from(reason.as_str())));
+	}
+	fields.push(("elapsed_ns", Json::from(part_run.time.as_nanos())));
+	fields.push(("stats", stats_json(&part_run.stats)));
+	Json::object(fields)
+}
+
+// `source` says where the input came from: a path, "stdin" or "example N"
+pub fn run_json(day: u8, source: &str, input: &str, run: &TimedRun) -> Json {
+	let parts = run.parts.iter().map(part_json).collect();
+
+	Json::object([
+		("day", Json::from(day)),
+		("input", Json::object([
+			("source", Json::from(source)),
+			("hash", Json::from(format!("{:016x}", hash_input(input)))),
+			("bytes", Json::from(input.len())),
+		])),
+		("parse", Json::object([
+			("elapsed_ns", Json::from(run.parse_time.as_nanos())),
+			("stats", stats_json(&run.parse_stats)),
+		])),
+		("parts", Json::Array(parts)),
+	])
+}
@@ -0,0 +1,155 @@
+use crate::rng::Rng;
+use crate::solution::{Answer, Solution};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+// an answer, or the message of the panic that happened instead
+pub type Outcome = Result<Answer, String>;
+
+// a generated input on which a part and its reference implementation don't agree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+	pub day: u8,
+	pub part: u8,
+	// of the generated input, before it was shrunk
+	pub size: usize,
+	pub seed: u64,
+	// the smallest input found that still diverges
+	pub input: String,
+	pub answer: Outcome,
+	pub reference: Outcome,
+}
+
+fn format_outcome(outcome: &Outcome) -> String {
+	match outcome {
+		Ok(answer) => answer.to_string(),
+		Err(message) => format!("a panic ({})", message),
+	}
+}
+
+impl fmt::Display for Divergence {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"Day {} part {} gives {} where the reference gives {}, on this input shrunk from the one generated with size {} and seed {}:",
+			self.day, self.part, format_outcome(&self.answer), format_outcome(&self.reference), self.size, self.seed
+		)?;
+		write!(f, "{}", self.input)
+	}
+}
+
+// the text given to panic!, for the panics caught with catch_unwind
+pub fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		message.to_string()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.clone()
+	} else {
+		"unknown panic".to_string()
+	}
+}
+
+// None when the input is not valid, which is not a divergence
+fn run_both<S: Solution>(input: &str, part: u8) -> Option<(Outcome, Outcome)> {
+	let input = S::parse(input).ok()?;
+	let solve = match part {
+		1 => S::part_1,
+		_ => S::part_2,
+	};
+
+	let answer = panic::catch_unwind(AssertUnwindSafe(|| solve(&input))).map_err(panic_message);
+	let reference = panic::catch_unwind(AssertUnwindSafe(|| S::reference(&input, part))).map_err(panic_message);
+	Some((answer, reference))
+}
+
+fn diverges<S: Solution>(input: &str, part: u8) -> bool {
+	run_both::<S>(input, part).is_some_and(|(answer, reference)| answer != reference)
+}
+
+// removes the biggest chunks of units it can while the input still diverges, halving the chunk size
+// down to single units
+fn shrink_units(mut units: Vec<&str>, join: impl Fn(&[&str]) -> String, mut diverges: impl FnMut(&str) -> bool) -> Vec<&str> {
+	let mut chunk_size = (units.len() / 2).max(1);
+	loop {
+		let mut start = 0;
+		while start < units.len() && units.len() > 1 {
+			let end = (start + chunk_size).min(units.len());
+			let candidate: Vec<&str> = units[..start].iter().chain(&units[end..]).copied().collect();
+			if !candidate.is_empty() && diverges(&join(&candidate)) {
+				units = candidate;
+			} else {
+				start += chunk_size;
+			}
+		}
+
+		if chunk_size == 1 {
+			return units;
+		}
+		chunk_size /= 2;
+	}
+}
+
+// removes blocks separated by empty lines, then lines, then the words of a single line input, as long
+// as the input keeps diverging. Cutting anything else would most likely break the input's format
+pub fn shrink(input: &str, mut diverges: impl FnMut(&str) -> bool) -> String {
+	let mut input = input.to_string();
+
+	for separator in ["\n\n", "\n", " "] {
+		let text = input.trim_end().to_string();
+		if separator == " " && text.contains('\n') {
+			continue;
+		}
+
+		let units: Vec<&str> = text.split(separator).collect();
+		let num_units = units.len();
+		let join = |units: &[&str]| units.join(separator) + "\n";
+		// only inputs that were checked to diverge are kept
+		let units = shrink_units(units, join, &mut diverges);
+		if units.len() < num_units {
+			input = join(&units);
+		}
+	}
+
+	input
+}
+
+// tries `seeds` inputs of every size from 1 to `max_size`, so that the first divergence found is on one
+// of the smallest generated inputs, and shrinks it
+pub fn find_divergence<S: Solution>(part: u8, max_size: usize, seeds: u64) -> Result<Option<Divergence>, String> {
+	if !S::REFERENCE_PARTS.contains(&part) {
+		return Err(format!("Day {} part {} has no reference implementation", S::DAY, part));
+	}
+	if S::generate(1, &mut Rng::new(0)).is_none() {
+		return Err(format!("Day {} has no input generator", S::DAY));
+	}
+
+	// the panics are part of the outcomes, don't also print them
+	let previous_hook = panic::take_hook();
+	panic::set_hook(Box::new(|_| {}));
+
+	let divergence = (1..=max_size)
+		.flat_map(|size| (0..seeds).map(move |seed| (size, seed)))
+		.find_map(|(size, seed)| {
+			let input = S::generate(size, &mut Rng::new(seed))?;
+			diverges::<S>(&input, part).then(|| (size, seed, shrink(&input, |input| diverges::<S>(input, part))))
+		})
+		.map(|(size, seed, input)| {
+			let (answer, reference) = run_both::<S>(&input, part).expect("A shrunk input is valid");
+			Divergence { day: S::DAY, part, size, seed, input, answer, reference }
+		});
+
+	panic::set_hook(previous_hook);
+	Ok(divergence)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn shrinks_lines_then_words() {
+		let input = "1 2\n3 4 5\n6\n";
+		let diverges = |input: &str| input.contains('4');
+		assert_eq!(shrink(input, diverges), "4\n");
+	}
+}
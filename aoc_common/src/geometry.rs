@@ -0,0 +1,232 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// (row, column), rows grow downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+	pub r: i32,
+	pub c: i32,
+}
+
+impl Pos {
+	pub const fn new(r: i32, c: i32) -> Self {
+		Pos { r, c }
+	}
+
+	pub fn manhattan(self, other: Pos) -> i32 {
+		(self.r - other.r).abs() + (self.c - other.c).abs()
+	}
+
+	pub fn step(self, dir: Dir) -> Pos {
+		self + dir.delta()
+	}
+}
+
+impl From<(i32, i32)> for Pos {
+	fn from((r, c): (i32, i32)) -> Self {
+		Pos { r, c }
+	}
+}
+
+impl From<Pos> for (i32, i32) {
+	fn from(pos: Pos) -> Self {
+		(pos.r, pos.c)
+	}
+}
+
+impl fmt::Display for Pos {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "({}, {})", self.r, self.c)
+	}
+}
+
+impl Add for Pos {
+	type Output = Pos;
+
+	fn add(self, rhs: Pos) -> Pos {
+		Pos::new(self.r + rhs.r, self.c + rhs.c)
+	}
+}
+
+impl Sub for Pos {
+	type Output = Pos;
+
+	fn sub(self, rhs: Pos) -> Pos {
+		Pos::new(self.r - rhs.r, self.c - rhs.c)
+	}
+}
+
+impl Mul<i32> for Pos {
+	type Output = Pos;
+
+	fn mul(self, rhs: i32) -> Pos {
+		Pos::new(self.r * rhs, self.c * rhs)
+	}
+}
+
+impl Neg for Pos {
+	type Output = Pos;
+
+	fn neg(self) -> Pos {
+		Pos::new(-self.r, -self.c)
+	}
+}
+
+impl AddAssign for Pos {
+	fn add_assign(&mut self, rhs: Pos) {
+		self.r += rhs.r;
+		self.c += rhs.c;
+	}
+}
+
+impl SubAssign for Pos {
+	fn sub_assign(&mut self, rhs: Pos) {
+		self.r -= rhs.r;
+		self.c -= rhs.c;
+	}
+}
+
+// map_size is (num rows, num columns)
+pub fn is_pos_in_bounds(pos: Pos, map_size: (i32, i32)) -> bool {
+	pos.r >= 0 && pos.r < map_size.0 && pos.c >= 0 && pos.c < map_size.1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+	Up,
+	Right,
+	Down,
+	Left
+}
+
+impl Dir {
+	// clockwise, starting at up
+	pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+	// '^', '>', 'v' or '<'
+	pub fn from_arrow(c: char) -> Option<Dir> {
+		match c {
+			'^' => Some(Dir::Up),
+			'>' => Some(Dir::Right),
+			'v' => Some(Dir::Down),
+			'<' => Some(Dir::Left),
+			_ => None
+		}
+	}
+
+	// 'N', 'E', 'S' or 'W', north is up
+	pub fn from_compass(c: char) -> Option<Dir> {
+		match c {
+			'N' => Some(Dir::Up),
+			'E' => Some(Dir::Right),
+			'S' => Some(Dir::Down),
+			'W' => Some(Dir::Left),
+			_ => None
+		}
+	}
+
+	pub fn compass(self) -> char {
+		match self {
+			Dir::Up => 'N',
+			Dir::Right => 'E',
+			Dir::Down => 'S',
+			Dir::Left => 'W'
+		}
+	}
+
+	pub fn arrow(self) -> char {
+		match self {
+			Dir::Up => '^',
+			Dir::Right => '>',
+			Dir::Down => 'v',
+			Dir::Left => '<'
+		}
+	}
+
+	pub fn turn_right(self) -> Dir {
+		match self {
+			Dir::Up => Dir::Right,
+			Dir::Right => Dir::Down,
+			Dir::Down => Dir::Left,
+			Dir::Left => Dir::Up
+		}
+	}
+
+	pub fn turn_left(self) -> Dir {
+		match self {
+			Dir::Up => Dir::Left,
+			Dir::Right => Dir::Up,
+			Dir::Down => Dir::Right,
+			Dir::Left => Dir::Down
+		}
+	}
+
+	pub fn opposite(self) -> Dir {
+		match self {
+			Dir::Up => Dir::Down,
+			Dir::Right => Dir::Left,
+			Dir::Down => Dir::Up,
+			Dir::Left => Dir::Right
+		}
+	}
+
+	pub fn is_horizontal(self) -> bool {
+		matches!(self, Dir::Right | Dir::Left)
+	}
+
+	// position change of a single step in this direction
+	pub fn delta(self) -> Pos {
+		match self {
+			Dir::Up => Pos::new(-1, 0),
+			Dir::Right => Pos::new(0, 1),
+			Dir::Down => Pos::new(1, 0),
+			Dir::Left => Pos::new(0, -1)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn turns() {
+		for dir in Dir::ALL {
+			assert_eq!(dir.turn_left().turn_right(), dir);
+			assert_eq!(dir.turn_right().turn_left(), dir);
+			assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+			assert_eq!(dir.turn_left().turn_left().turn_left().turn_left(), dir);
+			assert_eq!(dir.delta() + dir.opposite().delta(), Pos::default());
+		}
+		assert_eq!(Dir::ALL.map(Dir::turn_right), [Dir::Right, Dir::Down, Dir::Left, Dir::Up]);
+		assert_eq!(Dir::ALL.map(Dir::arrow).map(Dir::from_arrow), Dir::ALL.map(Some));
+		assert_eq!(Dir::ALL.map(Dir::compass).map(Dir::from_compass), Dir::ALL.map(Some));
+	}
+
+	#[test]
+	fn steps_off_the_edges() {
+		let map_size = (2, 3);
+		assert_eq!(Pos::new(0, 1).step(Dir::Up), Pos::new(-1, 1));
+		assert_eq!(Pos::new(1, 2).step(Dir::Right), Pos::new(1, 3));
+		assert_eq!(Pos::new(1, 0).step(Dir::Down), Pos::new(2, 0));
+		assert_eq!(Pos::new(0, 0).step(Dir::Left), Pos::new(0, -1));
+		for (pos, dir) in [(Pos::new(0, 1), Dir::Up), (Pos::new(1, 2), Dir::Right), (Pos::new(1, 0), Dir::Down), (Pos::new(0, 0), Dir::Left)] {
+			assert!(is_pos_in_bounds(pos, map_size));
+			assert!(!is_pos_in_bounds(pos.step(dir), map_size));
+			assert!(is_pos_in_bounds(pos.step(dir).step(dir.opposite()), map_size));
+		}
+	}
+
+	#[test]
+	fn arithmetic() {
+		let mut pos = Pos::new(2, -3);
+		assert_eq!(pos + Pos::new(1, 1), Pos::new(3, -2));
+		assert_eq!(pos - Pos::new(1, 1), Pos::new(1, -4));
+		assert_eq!(pos * 3, Pos::new(6, -9));
+		assert_eq!(-pos, Pos::new(-2, 3));
+		assert_eq!(pos.manhattan(Pos::new(0, 0)), 5);
+		pos += Pos::new(1, 2);
+		pos -= Pos::new(0, 1);
+		assert_eq!(pos, Pos::new(3, -2));
+	}
+}
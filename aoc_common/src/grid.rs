@@ -0,0 +1,294 @@
+use crate::geometry::{is_pos_in_bounds, Dir, Pos};
+use crate::parse::{first_problem, lines, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// (num rows, num columns), assuming every line has the same length as the first one
+pub fn get_map_size(input: &str) -> (i32, i32) {
+	let num_columns = input.chars().position(|c| c == '\n').unwrap_or(input.len()) as i32;
+	let num_rows = input.lines().count() as i32;
+	(num_rows, num_columns)
+}
+
+// positions of `symbol` in the text of a map, which can be found even when the lines don't all have
+// the same length
+pub fn find_in_text(input: &str, symbol: char) -> Vec<Pos> {
+	lines(input)
+		.flat_map(|line| line.chars().map(move |(column, c)| (line.number, column, c)))
+		.filter(|(_line, _column, c)| *c == symbol)
+		.map(|(line, column, _c)| Pos::new(line as i32 - 1, column as i32 - 1))
+		.collect()
+}
+
+pub fn array_idx_to_coord(array_idx: usize, num_columns: i32) -> Pos {
+	let array_idx = array_idx as i32;
+	Pos::new(array_idx / num_columns, array_idx % num_columns)
+}
+
+pub fn coord_to_array_idx(pos: Pos, num_columns: i32) -> usize {
+	(pos.r * num_columns + pos.c) as usize
+}
+
+// clockwise, starting at up
+pub const DELTAS_8: [Pos; 8] = [
+	Pos::new(-1, 0), Pos::new(-1, 1), Pos::new(0, 1), Pos::new(1, 1),
+	Pos::new(1, 0), Pos::new(1, -1), Pos::new(0, -1), Pos::new(-1, -1)
+];
+
+// a rectangular map, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	num_rows: i32,
+	num_columns: i32,
+	cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+	pub fn new(num_rows: i32, num_columns: i32, value: T) -> Self where T: Clone {
+		Grid {
+			num_rows,
+			num_columns,
+			cells: vec![value; (num_rows * num_columns) as usize],
+		}
+	}
+
+	pub fn from_cells(num_rows: i32, num_columns: i32, cells: Vec<T>) -> Self {
+		assert_eq!(cells.len(), (num_rows * num_columns) as usize, "Cell count does not match the grid size");
+		Grid { num_rows, num_columns, cells }
+	}
+
+	// every line has to have the same length, `cell` returns None for characters that are not allowed,
+	// which are reported as expecting `expected`
+	pub fn parse(input: &str, expected: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+		Grid::validate(input, expected, cell).map_err(first_problem)
+	}
+
+	// like parse, but goes on after a problem to report every character that is not allowed and every
+	// line that does not have the length of the first one
+	pub fn validate(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, Vec<ParseError>> {
+		let mut cells = Vec::new();
+		let mut problems = Vec::new();
+		let mut num_rows = 0;
+		let mut num_columns = None;
+
+		for line in lines(input) {
+			let mut line_length = 0;
+			for (column, c) in line.chars() {
+				match cell(c) {
+					Some(value) => cells.push(value),
+					None => problems.push(line.char_error(column, c, expected)),
+				}
+				line_length = column;
+			}
+
+			let num_columns = *num_columns.get_or_insert(line_length);
+			if line_length != num_columns {
+				let column = line_length.min(num_columns) + 1;
+				let text = line.text.get(column - 1..).unwrap_or("");
+				problems.push(ParseError::new(line.number, column, text, &format!("a line of {} characters", num_columns)));
+			}
+			num_rows += 1;
+		}
+
+		match num_columns {
+			Some(num_columns) if num_columns > 0 && problems.is_empty() => Ok(Grid::from_cells(num_rows, num_columns as i32, cells)),
+			Some(num_columns) if num_columns > 0 => Err(problems),
+			_ => Err(vec![ParseError::at_end(input, "a map")]),
+		}
+	}
+
+	// (num rows, num columns)
+	pub fn size(&self) -> (i32, i32) {
+		(self.num_rows, self.num_columns)
+	}
+
+	pub fn num_rows(&self) -> i32 {
+		self.num_rows
+	}
+
+	pub fn num_columns(&self) -> i32 {
+		self.num_columns
+	}
+
+	pub fn in_bounds(&self, pos: Pos) -> bool {
+		is_pos_in_bounds(pos, self.size())
+	}
+
+	pub fn get(&self, pos: Pos) -> Option<&T> {
+		if !self.in_bounds(pos) {
+			return None
+		}
+		Some(&self.cells[coord_to_array_idx(pos, self.num_columns)])
+	}
+
+	pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+		if !self.in_bounds(pos) {
+			return None
+		}
+		Some(&mut self.cells[coord_to_array_idx(pos, self.num_columns)])
+	}
+
+	// every position, row by row
+	pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+		let num_columns = self.num_columns;
+		(0..self.num_rows).flat_map(move |row| (0..num_columns).map(move |column| Pos::new(row, column)))
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+		self.positions().zip(self.cells.iter())
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		self.cells.chunks(self.num_columns as usize)
+	}
+
+	pub fn row(&self, row: i32) -> &[T] {
+		assert!(row >= 0 && row < self.num_rows, "Row out of bounds");
+		let start = (row * self.num_columns) as usize;
+		&self.cells[start..start + self.num_columns as usize]
+	}
+
+	pub fn column(&self, column: i32) -> impl Iterator<Item = &T> {
+		assert!(column >= 0 && column < self.num_columns, "Column out of bounds");
+		self.cells.iter().skip(column as usize).step_by(self.num_columns as usize)
+	}
+
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		(0..self.num_columns).map(|column| self.column(column))
+	}
+
+	// in bounds neighbours, up, right, down, left
+	pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+		Dir::ALL.into_iter()
+			.map(move |dir| pos + dir.delta())
+			.filter(|new_pos| self.in_bounds(*new_pos))
+	}
+
+	// in bounds neighbours, including the diagonal ones
+	pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+		DELTAS_8.into_iter()
+			.map(move |delta| pos + delta)
+			.filter(|new_pos| self.in_bounds(*new_pos))
+	}
+
+	// first position holding `value`, row by row
+	pub fn find(&self, value: &T) -> Option<Pos> where T: PartialEq {
+		self.cells.iter()
+			.position(|cell| cell == value)
+			.map(|array_idx| array_idx_to_coord(array_idx, self.num_columns))
+	}
+
+	pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a where T: PartialEq {
+		self.iter()
+			.filter(move |(_pos, cell)| *cell == value)
+			.map(|(pos, _cell)| pos)
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid::from_cells(self.num_rows, self.num_columns, self.cells.iter().map(f).collect())
+	}
+
+	// the positions on the edge of the map
+	pub fn border(&self) -> impl Iterator<Item = Pos> + use<T> {
+		let (num_rows, num_columns) = self.size();
+		self.positions().filter(move |pos| pos.r == 0 || pos.c == 0 || pos.r == num_rows - 1 || pos.c == num_columns - 1)
+	}
+}
+
+impl Grid<char> {
+	// any character is a valid cell
+	pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+		Grid::parse(input, "any character", Some)
+	}
+}
+
+impl<T> Index<Pos> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, pos: Pos) -> &T {
+		self.get(pos).unwrap_or_else(|| panic!("Position {} out of the map bounds", pos))
+	}
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+	fn index_mut(&mut self, pos: Pos) -> &mut T {
+		self.get_mut(pos).unwrap_or_else(|| panic!("Position {} out of the map bounds", pos))
+	}
+}
+
+// one line per row, without separators between cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for row in self.rows() {
+			for cell in row {
+				write!(f, "{}", cell)?;
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn digit(c: char) -> Option<u32> {
+		c.to_digit(10)
+	}
+
+	#[test]
+	fn parse() {
+		let grid = Grid::parse("123\n456\n", "a digit", digit).unwrap();
+		assert_eq!(grid.size(), (2, 3));
+		assert_eq!(grid[Pos::new(1, 2)], 6);
+		assert_eq!(grid.row(0), &[1, 2, 3]);
+		assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+		assert_eq!(grid.to_string(), "123\n456\n");
+	}
+
+	#[test]
+	fn ragged_rows() {
+		let problems = Grid::validate("123\n45\n6789\n", "a digit", digit).unwrap_err();
+		assert_eq!(problems, vec![
+			ParseError::new(2, 3, "", "a line of 3 characters"),
+			ParseError::new(3, 4, "9", "a line of 3 characters"),
+		]);
+		assert_eq!(Grid::parse("123\n45\n", "a digit", digit), Err(ParseError::new(2, 3, "", "a line of 3 characters")));
+	}
+
+	#[test]
+	fn characters_not_allowed() {
+		let problems = Grid::validate("1x3\n45y\n", "a digit", digit).unwrap_err();
+		assert_eq!(problems, vec![
+			ParseError::new(1, 2, "x", "a digit"),
+			ParseError::new(2, 3, "y", "a digit"),
+		]);
+	}
+
+	#[test]
+	fn empty_input() {
+		assert_eq!(Grid::parse("", "a digit", digit), Err(ParseError::new(1, 1, "", "a map")));
+		assert_eq!(Grid::parse("\n\n", "a digit", digit), Err(ParseError::new(3, 1, "", "a map")));
+	}
+
+	#[test]
+	fn out_of_bounds() {
+		let mut grid = Grid::parse_chars("ab\ncd\n").unwrap();
+		for pos in [Pos::new(-1, 0), Pos::new(0, -1), Pos::new(2, 0), Pos::new(0, 2)] {
+			assert!(!grid.in_bounds(pos));
+			assert_eq!(grid.get(pos), None);
+			assert_eq!(grid.get_mut(pos), None);
+		}
+		assert_eq!(grid.get(Pos::new(1, 1)), Some(&'d'));
+		assert_eq!(grid.neighbours_4(Pos::new(0, 0)).collect::<Vec<_>>(), vec![Pos::new(0, 1), Pos::new(1, 0)]);
+		assert_eq!(grid.neighbours_8(Pos::new(0, 0)).count(), 3);
+	}
+
+	#[test]
+	#[should_panic(expected = "Position (2, 0) out of the map bounds")]
+	fn index_out_of_bounds() {
+		let grid = Grid::parse_chars("ab\ncd\n").unwrap();
+		let _cell = grid[Pos::new(2, 0)];
+	}
+}
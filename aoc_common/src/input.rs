@@ -0,0 +1,159 @@
+use crate::log;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// where the default inputs are looked for, see default_input_path
+pub const INPUTS_DIR: &str = "inputs";
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+#[derive(Debug)]
+pub enum InputError {
+	Read { path: PathBuf, error: io::Error },
+	Stdin(io::Error),
+	NoDefault { day: u8, searched: Vec<PathBuf> },
+}
+
+impl fmt::Display for InputError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			InputError::Read { path, error } => write!(f, "Could not read input file {}: {}", path.display(), error),
+			InputError::Stdin(error) => write!(f, "Could not read the input from stdin: {}", error),
+			InputError::NoDefault { day, searched } => {
+				write!(f, "No input file for day {}, looked for", day)?;
+				for path in searched {
+					write!(f, " {}", path.display())?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl InputError {
+	// the input file does not exist, as opposed to existing but not being readable
+	pub fn is_missing(&self) -> bool {
+		match self {
+			InputError::Read { error, .. } => error.kind() == io::ErrorKind::NotFound,
+			InputError::Stdin(_) => false,
+			InputError::NoDefault { .. } => true,
+		}
+	}
+}
+
+impl Error for InputError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+	File(PathBuf),
+	Stdin,
+}
+
+impl InputSource {
+	// "-" is stdin, anything else is a file path
+	pub fn from_arg(arg: &str) -> InputSource {
+		match arg {
+			"-" => InputSource::Stdin,
+			_ => InputSource::File(PathBuf::from(arg)),
+		}
+	}
+
+	pub fn read(&self) -> Result<String, InputError> {
+		match self {
+			InputSource::File(path) => fs::read_to_string(path).map_err(|error| InputError::Read { path: path.clone(), error }),
+			InputSource::Stdin => {
+				let mut input = String::new();
+				io::stdin().read_to_string(&mut input).map_err(InputError::Stdin)?;
+				Ok(input)
+			}
+		}
+	}
+
+	// for the inputs too large to be read at once
+	pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+		match self {
+			InputSource::File(path) => {
+				let file = fs::File::open(path).map_err(|error| InputError::Read { path: path.clone(), error })?;
+				Ok(Box::new(BufReader::new(file)))
+			}
+			InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+		}
+	}
+}
+
+impl fmt::Display for InputSource {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			InputSource::File(path) => write!(f, "{}", path.display()),
+			InputSource::Stdin => write!(f, "stdin"),
+		}
+	}
+}
+
+pub fn input_file_name(day: u8) -> String {
+	format!("day_{:02}.txt", day)
+}
+
+// $AOC_INPUTS/day_NN.txt if that variable is set, otherwise the first inputs/day_NN.txt found in
+// the current directory or one of its parents, so that it works from the workspace root or a day's directory
+pub fn default_input_path(day: u8) -> Result<PathBuf, InputError> {
+	let file_name = input_file_name(day);
+
+	let searched: Vec<PathBuf> = match env::var_os(INPUTS_DIR_VAR) {
+		Some(dir) => vec![Path::new(&dir).join(&file_name)],
+		None => {
+			let current_dir = env::current_dir().unwrap_or_default();
+			current_dir.ancestors()
+				.map(|dir| dir.join(INPUTS_DIR).join(&file_name))
+				.collect()
+		}
+	};
+
+	match searched.iter().find(|path| path.is_file()) {
+		Some(path) => Ok(path.clone()),
+		None => Err(InputError::NoDefault { day, searched }),
+	}
+}
+
+// an explicit path, "-" for stdin, or the default input of the day when there's no argument
+pub fn resolve_input_source(arg: Option<&str>, day: u8) -> Result<InputSource, InputError> {
+	match arg {
+		Some(arg) => Ok(InputSource::from_arg(arg)),
+		None => Ok(InputSource::File(default_input_path(day)?)),
+	}
+}
+
+pub fn resolve_input(arg: Option<&str>, day: u8) -> Result<String, InputError> {
+	resolve_input_source(arg, day)?.read()
+}
+
+// for the day binaries, which take the input as their only, optional, argument, next to the -q / -v
+// flags of the log
+pub fn read_day_input(day: u8) -> Result<String, InputError> {
+	let args = log::apply_verbosity_flags(env::args().skip(1));
+	resolve_input(args.first().map(String::as_str), day)
+}
+
+// error type for the `main` of the day binaries: returning an error from `main` prints it with Debug,
+// which prints the error message here instead of the error's fields
+pub struct MainError(Box<dyn Error>);
+
+impl<E: Error + 'static> From<E> for MainError {
+	fn from(error: E) -> Self {
+		MainError(Box::new(error))
+	}
+}
+
+impl fmt::Debug for MainError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+// 64-bit FNV-1a, stable across runs and platforms, so it can be stored next to answers
+pub fn hash_input(input: &str) -> u64 {
+	input.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
@@ -0,0 +1,12 @@
+pub mod diff;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod params;
+pub mod parse;
+pub mod render;
+pub mod rng;
+pub mod simulation;
+pub mod solution;
+pub mod stats;
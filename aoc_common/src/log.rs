@@ -0,0 +1,128 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Progress and debugging messages of the solvers and the runner. They go to stderr, so that stdout
+// only has the answers, and only the ones up to the level set with -q / -v are written:
+// -q errors only, info by default, -v adds debug and -vv trace
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+	Error,
+	Info,
+	Debug,
+	Trace,
+}
+
+impl fmt::Display for Level {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Level::Error => write!(f, "error"),
+			Level::Info => write!(f, "info"),
+			Level::Debug => write!(f, "debug"),
+			Level::Trace => write!(f, "trace"),
+		}
+	}
+}
+
+const LEVELS: [Level; 4] = [Level::Error, Level::Info, Level::Debug, Level::Trace];
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level(level: Level) {
+	MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+	LEVELS[MAX_LEVEL.load(Ordering::Relaxed) as usize]
+}
+
+pub fn enabled(level: Level) -> bool {
+	level <= self::level()
+}
+
+// use the macros, which don't even evaluate their arguments when the level is off
+pub fn write(level: Level, message: fmt::Arguments) {
+	// maps printed with Display already end with a line break
+	let message = message.to_string();
+	let message = message.trim_end_matches('\n');
+	match level {
+		Level::Info => eprintln!("{}", message),
+		_ => eprintln!("{}: {}", level, message),
+	}
+}
+
+// sets the level from the -q, -v and -vv flags found anywhere in the arguments, and returns the others
+pub fn apply_verbosity_flags(args: impl IntoIterator<Item = String>) -> Vec<String> {
+	let mut level = Level::Info as usize;
+	let mut other_args = Vec::new();
+
+	for arg in args {
+		match arg.as_str() {
+			"-q" => level = Level::Error as usize,
+			"-v" => level += 1,
+			"-vv" => level += 2,
+			_ => other_args.push(arg),
+		}
+	}
+
+	set_level(LEVELS[level.min(Level::Trace as usize)]);
+	other_args
+}
+
+#[macro_export]
+macro_rules! error {
+	($($arg:tt)*) => {
+		if $crate::log::enabled($crate::log::Level::Error) {
+			$crate::log::write($crate::log::Level::Error, format_args!($($arg)*))
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! info {
+	($($arg:tt)*) => {
+		if $crate::log::enabled($crate::log::Level::Info) {
+			$crate::log::write($crate::log::Level::Info, format_args!($($arg)*))
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! debug {
+	($($arg:tt)*) => {
+		if $crate::log::enabled($crate::log::Level::Debug) {
+			$crate::log::write($crate::log::Level::Debug, format_args!($($arg)*))
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! trace {
+	($($arg:tt)*) => {
+		if $crate::log::enabled($crate::log::Level::Trace) {
+			$crate::log::write($crate::log::Level::Trace, format_args!($($arg)*))
+		}
+	};
+}
+
+// so that they can be used as log::info! and so on
+pub use crate::{debug, error, info, trace};
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn verbosity_flags() {
+		let args = ["run", "-vv", "--day", "16"].map(String::from);
+		assert_eq!(apply_verbosity_flags(args), ["run", "--day", "16"]);
+		assert_eq!(level(), Level::Trace);
+		assert!(enabled(Level::Debug));
+
+		apply_verbosity_flags(["-q".to_string()]);
+		assert!(!enabled(Level::Info));
+
+		apply_verbosity_flags([]);
+		assert_eq!(level(), Level::Info);
+	}
+}
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+// name=value pairs given with --param, in the order they were given
+pub type Overrides = [(String, String)];
+
+// The constants of a puzzle, the puzzle's values by default, that the runner and the tests can set by
+// name to try the solutions on other variants of the puzzle
+pub trait Params: Default {
+	// the name and description of every parameter
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+	// the value of a parameter the way set takes it, None when it is not set or the day doesn't have it
+	fn get(&self, name: &str) -> Option<String>;
+}
+
+// for the days that have no parameters
+impl Params for () {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[];
+
+	fn set(&mut self, name: &str, _value: &str) -> Result<(), String> {
+		Err(unknown(name))
+	}
+
+	fn get(&self, _name: &str) -> Option<String> {
+		None
+	}
+}
+
+pub fn unknown(name: &str) -> String {
+	format!("Unknown parameter {}", name)
+}
+
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+	value.parse().map_err(|_| format!("Invalid value for {}: {}", name, value))
+}
+
+// the defaults, with the overrides set one after the other
+pub fn with_overrides<P: Params>(overrides: &Overrides) -> Result<P, String> {
+	let mut params = P::default();
+	for (name, value) in overrides {
+		params.set(name, value)?;
+	}
+	Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn overrides() {
+		let overrides = [("blinks".to_string(), "6".to_string())];
+		assert_eq!(with_overrides::<()>(&overrides), Err("Unknown parameter blinks".to_string()));
+		assert_eq!(with_overrides::<()>(&[]), Ok(()));
+	}
+}
@@ -0,0 +1,100 @@
+use crate::geometry::Pos;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// line and column are 1-based, like in a text editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+	pub line: usize,
+	pub column: usize,
+	pub text: String,
+	pub expected: String,
+}
+
+impl ParseError {
+	pub fn new(line: usize, column: usize, text: &str, expected: &str) -> Self {
+		ParseError {
+			line,
+			column,
+			text: text.to_string(),
+			expected: expected.to_string(),
+		}
+	}
+
+	// for the cell at `pos` of a map, which is on line pos.r + 1
+	pub fn at_cell(pos: Pos, c: char, expected: &str) -> Self {
+		ParseError::new(pos.r as usize + 1, pos.c as usize + 1, &c.to_string(), expected)
+	}
+
+	// for things that are missing from the input altogether, points just past the last line
+	pub fn at_end(input: &str, expected: &str) -> Self {
+		ParseError::new(input.lines().count() + 1, 1, "", expected)
+	}
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+		if self.text.is_empty() {
+			write!(f, "found nothing")
+		} else {
+			write!(f, "found \"{}\"", self.text)
+		}
+	}
+}
+
+impl Error for ParseError {}
+
+// for the validating parsers, which report every problem in the input, when only the first one is needed
+pub fn first_problem(problems: Vec<ParseError>) -> ParseError {
+	problems.into_iter().next().expect("A failed validation reports at least one problem")
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+	pub number: usize,
+	pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+	input.lines()
+		.enumerate()
+		.map(|(i, text)| Line { number: i + 1, text })
+}
+
+impl<'a> Line<'a> {
+	// `part` has to be a slice of this line's text, otherwise the first column is reported
+	pub fn column_of(&self, part: &str) -> usize {
+		let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+		if offset <= self.text.len() { offset + 1 } else { 1 }
+	}
+
+	pub fn error(&self, part: &str, expected: &str) -> ParseError {
+		ParseError::new(self.number, self.column_of(part), part, expected)
+	}
+
+	pub fn parse<T: FromStr>(&self, part: &'a str, expected: &str) -> Result<T, ParseError> {
+		part.parse().map_err(|_| self.error(part, expected))
+	}
+
+	pub fn split_once(&self, part: &'a str, delimiter: &str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+		part.split_once(delimiter).ok_or_else(|| self.error(part, expected))
+	}
+
+	pub fn strip_prefix(&self, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+		part.strip_prefix(prefix).ok_or_else(|| {
+			let found = &part[..part.char_indices().nth(prefix.chars().count()).map_or(part.len(), |(i, _)| i)];
+			self.error(found, &format!("\"{}\"", prefix))
+		})
+	}
+
+	// (1-based column, char)
+	pub fn chars(self) -> impl Iterator<Item = (usize, char)> + 'a {
+		self.text.chars().enumerate().map(|(i, c)| (i + 1, c))
+	}
+
+	pub fn char_error(&self, column: usize, c: char, expected: &str) -> ParseError {
+		ParseError::new(self.number, column, &c.to_string(), expected)
+	}
+}
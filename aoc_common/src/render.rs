@@ -0,0 +1,190 @@
+use crate::geometry::Pos;
+use crate::grid::Grid;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+	pub const BLACK: Rgb = Rgb(0, 0, 0);
+	pub const WHITE: Rgb = Rgb(255, 255, 255);
+	pub const GREY: Rgb = Rgb(128, 128, 128);
+	pub const DARK_GREY: Rgb = Rgb(48, 48, 48);
+	pub const RED: Rgb = Rgb(220, 40, 40);
+	pub const GREEN: Rgb = Rgb(40, 180, 60);
+	pub const BLUE: Rgb = Rgb(50, 90, 220);
+	pub const YELLOW: Rgb = Rgb(240, 210, 40);
+	pub const ORANGE: Rgb = Rgb(240, 140, 30);
+	pub const BROWN: Rgb = Rgb(140, 90, 40);
+
+	// perceived brightness, for the grey images
+	pub fn luma(self) -> u8 {
+		((299 * self.0 as u32 + 587 * self.1 as u32 + 114 * self.2 as u32) / 1000) as u8
+	}
+
+	// `amount` of `other` over self, from 0 (self) to 255 (other)
+	pub fn mix(self, other: Rgb, amount: u8) -> Rgb {
+		let channel = |a: u8, b: u8| ((a as u32 * (255 - amount as u32) + b as u32 * amount as u32) / 255) as u8;
+		Rgb(channel(self.0, other.0), channel(self.1, other.1), channel(self.2, other.2))
+	}
+}
+
+// a colour for every region id, neighbouring ids getting clearly different hues
+pub fn region_colour(id: usize) -> Rgb {
+	// golden ratio steps around the hue circle
+	let hue = (id as f64 * 0.618_033_988_75).fract() * 6.0;
+	let x = 1.0 - (hue % 2.0 - 1.0).abs();
+	let (r, g, b) = match hue as u32 {
+		0 => (1.0, x, 0.0),
+		1 => (x, 1.0, 0.0),
+		2 => (0.0, 1.0, x),
+		3 => (0.0, x, 1.0),
+		4 => (x, 0.0, 1.0),
+		_ => (1.0, 0.0, x),
+	};
+	let channel = |value: f64| (60.0 + value * 170.0) as u8;
+	Rgb(channel(r), channel(g), channel(b))
+}
+
+// black for 0, through red and yellow, to white for `max` and above
+pub fn heat(value: u64, max: u64) -> Rgb {
+	if max == 0 {
+		return Rgb::BLACK;
+	}
+	let level = (value.min(max) * 765 / max) as u32;
+	let channel = |offset: u32| level.saturating_sub(offset).min(255) as u8;
+	Rgb(channel(0), channel(255), channel(510))
+}
+
+// colours for the characters of a map, `default` for the others
+pub fn char_palette(colours: &[(char, Rgb)], default: Rgb) -> impl Fn(&char) -> Rgb + '_ {
+	move |c| colours.iter()
+		.find(|(symbol, _colour)| symbol == c)
+		.map_or(default, |(_symbol, colour)| *colour)
+}
+
+// A grid state as an image, one square of `scale` pixels per cell. Written as a binary PPM, or as a PGM
+// when saved with the .pgm extension, both of which most image viewers open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+	cells: Grid<Rgb>,
+	scale: usize,
+}
+
+impl Image {
+	// (num rows, num columns)
+	pub fn new(size: (i32, i32), background: Rgb) -> Self {
+		Image {
+			cells: Grid::new(size.0, size.1, background),
+			scale: 1,
+		}
+	}
+
+	pub fn from_grid<T>(grid: &Grid<T>, palette: impl FnMut(&T) -> Rgb) -> Self {
+		Image {
+			cells: grid.map(palette),
+			scale: 1,
+		}
+	}
+
+	// pixels per cell side
+	pub fn with_scale(mut self, scale: usize) -> Self {
+		assert!(scale > 0, "The scale has to be at least 1");
+		self.scale = scale;
+		self
+	}
+
+	pub fn size(&self) -> (i32, i32) {
+		self.cells.size()
+	}
+
+	pub fn get(&self, pos: Pos) -> Option<Rgb> {
+		self.cells.get(pos).copied()
+	}
+
+	// positions out of the image are ignored, so that overlays don't have to be clipped first
+	pub fn set(&mut self, pos: Pos, colour: Rgb) {
+		if let Some(cell) = self.cells.get_mut(pos) {
+			*cell = colour;
+		}
+	}
+
+	// paints over the cells
+	pub fn overlay(&mut self, positions: impl IntoIterator<Item = Pos>, colour: Rgb) {
+		for pos in positions {
+			self.set(pos, colour);
+		}
+	}
+
+	// half blends `colour` into the cells, so that what's below stays visible
+	pub fn tint(&mut self, positions: impl IntoIterator<Item = Pos>, colour: Rgb) {
+		for pos in positions {
+			if let Some(cell) = self.cells.get_mut(pos) {
+				*cell = cell.mix(colour, 128);
+			}
+		}
+	}
+
+	// (width, height) in pixels
+	pub fn pixel_size(&self) -> (usize, usize) {
+		(self.cells.num_columns() as usize * self.scale, self.cells.num_rows() as usize * self.scale)
+	}
+
+	fn pixels(&self) -> impl Iterator<Item = Rgb> + '_ {
+		self.cells.rows()
+			.flat_map(|row| std::iter::repeat_n(row, self.scale))
+			.flat_map(|row| row.iter().flat_map(|cell| std::iter::repeat_n(*cell, self.scale)))
+	}
+
+	pub fn to_ppm(&self) -> Vec<u8> {
+		let (width, height) = self.pixel_size();
+		let mut bytes = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+		bytes.extend(self.pixels().flat_map(|Rgb(r, g, b)| [r, g, b]));
+		bytes
+	}
+
+	pub fn to_pgm(&self) -> Vec<u8> {
+		let (width, height) = self.pixel_size();
+		let mut bytes = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+		bytes.extend(self.pixels().map(Rgb::luma));
+		bytes
+	}
+
+	// grey for a .pgm path, colour otherwise
+	pub fn save(&self, path: &Path) -> io::Result<()> {
+		let bytes = match path.extension() {
+			Some(extension) if extension == "pgm" => self.to_pgm(),
+			_ => self.to_ppm(),
+		};
+		fs::write(path, bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn scaled_ppm() {
+		let grid = Grid::parse_chars("#.\n").unwrap();
+		let mut image = Image::from_grid(&grid, char_palette(&[('#', Rgb::WHITE)], Rgb::BLACK)).with_scale(2);
+		image.overlay([Pos::new(0, 1), Pos::new(5, 5)], Rgb(1, 2, 3));
+
+		let mut expected = b"P6\n4 2\n255\n".to_vec();
+		for _row in 0..2 {
+			expected.extend([255, 255, 255, 255, 255, 255, 1, 2, 3, 1, 2, 3]);
+		}
+		assert_eq!(image.to_ppm(), expected);
+		assert_eq!(&image.to_pgm()[11..15], [255, 255, 1, 1]);
+	}
+
+	#[test]
+	fn colours() {
+		assert_eq!(heat(0, 10), Rgb::BLACK);
+		assert_eq!(heat(10, 10), Rgb::WHITE);
+		assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 255), Rgb::WHITE);
+		assert_ne!(region_colour(0), region_colour(1));
+	}
+}
@@ -0,0 +1,62 @@
+// splitmix64, small and seedable, so that generated inputs are the same on every run and platform
+#[derive(Debug, Clone)]
+pub struct Rng {
+	state: u64,
+}
+
+impl Rng {
+	pub fn new(seed: u64) -> Self {
+		Rng { state: seed }
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	// in 0..n, the bias is negligible for the small n used by the generators
+	pub fn below(&mut self, n: usize) -> usize {
+		assert!(n > 0, "Empty range");
+		((self.next_u64() as u128 * n as u128) >> 64) as usize
+	}
+
+	// in low..=high
+	pub fn between(&mut self, low: i64, high: i64) -> i64 {
+		assert!(low <= high, "Empty range");
+		low + ((self.next_u64() as u128 * (high - low + 1) as u128) >> 64) as i64
+	}
+
+	pub fn chance(&mut self, probability: f64) -> bool {
+		((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+	}
+
+	pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.below(items.len())]
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.below(i + 1));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn deterministic() {
+		let numbers = |seed| {
+			let mut rng = Rng::new(seed);
+			(0..5).map(|_| rng.between(-3, 3)).collect::<Vec<_>>()
+		};
+
+		assert_eq!(numbers(7), numbers(7));
+		assert_ne!(numbers(7), numbers(8));
+		assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+	}
+}
@@ -0,0 +1,16 @@
+use crate::grid::Grid;
+
+// A puzzle that advances one step at a time, see Solution::simulation. The runner draws the frames
+// and highlights the cells that changed since the previous one
+pub trait Simulation {
+	// advances by one step, false once the simulation is over and nothing changed
+	fn step(&mut self) -> bool;
+
+	// the current state, one character per cell
+	fn frame(&self) -> Grid<char>;
+
+	// a line shown under the frame, like the step number
+	fn status(&self) -> String {
+		String::new()
+	}
+}
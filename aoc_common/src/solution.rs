@@ -0,0 +1,287 @@
+use crate::diff::{self, Divergence};
+use crate::params::{self, Overrides, Params};
+use crate::parse::ParseError;
+use crate::render::Image;
+use crate::rng::Rng;
+use crate::simulation::Simulation;
+use crate::stats::{self, Stat};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+	Number(i128),
+	Text(String),
+	// no answer, with the reason: the part is not implemented, the input has no solution, ...
+	Failed(String),
+}
+
+impl Answer {
+	pub fn is_failed(&self) -> bool {
+		matches!(self, Answer::Failed(_))
+	}
+}
+
+impl fmt::Display for Answer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Answer::Number(number) => write!(f, "{}", number),
+			Answer::Text(text) => write!(f, "{}", text),
+			Answer::Failed(reason) => write!(f, "no answer, {}", reason),
+		}
+	}
+}
+
+macro_rules! impl_answer_from_number {
+	($($t:ty),*) => {
+		$(impl From<$t> for Answer {
+			fn from(number: $t) -> Self {
+				Answer::Number(number as i128)
+			}
+		})*
+	};
+}
+
+impl_answer_from_number!(u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+	fn from(text: String) -> Self {
+		Answer::Text(text)
+	}
+}
+
+// A day's puzzle: the input is parsed once and then shared by both parts
+pub trait Solution {
+	const DAY: u8;
+	// number of implemented parts, starting from part 1
+	const PARTS: u8 = 2;
+	// parts that also have a slow, obviously correct implementation, see `reference`
+	const REFERENCE_PARTS: &'static [u8] = &[];
+
+	type Input;
+	// the puzzle's constants, () for the days that have none
+	type Params: Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+	// parse with other values than the puzzle's for the parameters, which the days that have some keep
+	// in their input
+	fn parse_with(input: &str, _params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Self::parse(input)
+	}
+
+	fn part_1(input: &Self::Input) -> Answer;
+
+	fn part_2(_input: &Self::Input) -> Answer {
+		Answer::Failed(format!("day {} part 2 is not implemented", Self::DAY))
+	}
+
+	// the test_input_N fixtures embedded in the day
+	fn example(n: usize) -> Option<&'static str>;
+
+	// every problem in the input, for `run --check`. Only the one parse stops at, unless the day has
+	// a validating parser
+	fn check(input: &str) -> Vec<ParseError> {
+		Self::parse(input).err().into_iter().collect()
+	}
+
+	// named pictures of the puzzle's state, for days where a dump in the terminal is unreadable
+	fn render(_input: &Self::Input) -> Vec<(&'static str, Image)> {
+		Vec::new()
+	}
+
+	// the steps taken to solve `part`, for the days that are step by step simulations
+	fn simulation(_input: &Self::Input, _part: u8) -> Option<Box<dyn Simulation>> {
+		None
+	}
+
+	// a random input in the puzzle's format, `size` being the day's main dimension (number of lines,
+	// side of the map, ...)
+	fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+		None
+	}
+
+	// the answer of `part` computed the straightforward way, to check the real solution against on
+	// generated inputs, see diff::find_divergence
+	fn reference(_input: &Self::Input, part: u8) -> Answer {
+		Answer::Failed(format!("day {} part {} has no reference implementation", Self::DAY, part))
+	}
+}
+
+// answers of a run, with the wall time taken and the stats recorded by each phase
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+	pub parse_time: Duration,
+	pub parse_stats: Vec<Stat>,
+	// in the order the parts were requested
+	pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+	pub part: u8,
+	pub answer: Answer,
+	pub time: Duration,
+	pub stats: Vec<Stat>,
+}
+
+// named images, see Solution::render
+pub type Renders = Vec<(&'static str, Image)>;
+
+// see Solution::simulation
+pub type Simulate = fn(&str, u8, &Overrides) -> Result<Option<Box<dyn Simulation>>, ParseError>;
+
+// Type erased Solution, so that days with different input types can be listed together
+pub struct Day {
+	pub day: u8,
+	pub parts: u8,
+	pub reference_parts: &'static [u8],
+	pub example: fn(usize) -> Option<&'static str>,
+	// the name and description of every parameter, see Params
+	pub params: &'static [(&'static str, &'static str)],
+	param_default: fn(&str) -> Option<String>,
+	solve: fn(&str, &[u8], &Overrides) -> Result<TimedRun, ParseError>,
+	check: fn(&str) -> Vec<ParseError>,
+	check_params: fn(&Overrides) -> Result<(), String>,
+	render: fn(&str, &Overrides) -> Result<Renders, ParseError>,
+	simulate: Simulate,
+	generate: fn(usize, &mut Rng) -> Option<String>,
+	find_divergence: fn(u8, usize, u64) -> Result<Option<Divergence>, String>,
+}
+
+impl Day {
+	pub const fn of<S: Solution>() -> Day {
+		Day {
+			day: S::DAY,
+			parts: S::PARTS,
+			reference_parts: S::REFERENCE_PARTS,
+			example: S::example,
+			params: S::Params::DESCRIPTIONS,
+			param_default: |name| S::Params::default().get(name),
+			solve: solve::<S>,
+			check: S::check,
+			check_params: |overrides| params::with_overrides::<S::Params>(overrides).map(|_params| ()),
+			render: |input, overrides| Ok(S::render(&parse::<S>(input, overrides)?)),
+			simulate: |input, part, overrides| Ok(S::simulation(&parse::<S>(input, overrides)?, part)),
+			generate: S::generate,
+			find_divergence: diff::find_divergence::<S>,
+		}
+	}
+
+	pub fn has_part(&self, part: u8) -> bool {
+		part >= 1 && part <= self.parts
+	}
+
+	// parses the input once and returns the answers of the requested parts, in the same order, with
+	// the puzzle's parameters
+	pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+		let run = self.solve_timed(input, parts, &[])?;
+		Ok(run.parts.into_iter().map(|part_run| part_run.answer).collect())
+	}
+
+	// the overrides of this method and the next ones have to pass check_params first
+	pub fn solve_timed(&self, input: &str, parts: &[u8], overrides: &Overrides) -> Result<TimedRun, ParseError> {
+		assert!(parts.iter().all(|&part| self.has_part(part)), "Day {} does not have parts {:?}", self.day, parts);
+		(self.solve)(input, parts, overrides)
+	}
+
+	// empty for a valid input
+	pub fn check(&self, input: &str) -> Vec<ParseError> {
+		(self.check)(input)
+	}
+
+	// the puzzle's value of a parameter, None when it is not set by default
+	pub fn param_default(&self, name: &str) -> Option<String> {
+		(self.param_default)(name)
+	}
+
+	// an error for the names the day doesn't have and the values that don't parse
+	pub fn check_params(&self, overrides: &Overrides) -> Result<(), String> {
+		(self.check_params)(overrides)
+	}
+
+	// empty for days that don't draw anything
+	pub fn render(&self, input: &str, overrides: &Overrides) -> Result<Renders, ParseError> {
+		(self.render)(input, overrides)
+	}
+
+	// None for days that are not simulations
+	pub fn simulation(&self, input: &str, part: u8, overrides: &Overrides) -> Result<Option<Box<dyn Simulation>>, ParseError> {
+		(self.simulate)(input, part, overrides)
+	}
+
+	// the same seed always gives the same input. None for days without a generator
+	pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+		(self.generate)(size, &mut Rng::new(seed))
+	}
+
+	// see diff::find_divergence
+	pub fn find_divergence(&self, part: u8, max_size: usize, seeds: u64) -> Result<Option<Divergence>, String> {
+		(self.find_divergence)(part, max_size, seeds)
+	}
+}
+
+// the overrides were checked by Day::check_params
+fn parse<S: Solution>(input: &str, overrides: &Overrides) -> Result<S::Input, ParseError> {
+	let params = params::with_overrides(overrides).unwrap_or_else(|error| panic!("Day {}: {}", S::DAY, error));
+	S::parse_with(input, &params)
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8], overrides: &Overrides) -> Result<TimedRun, ParseError> {
+	// drop anything left over from an earlier run that failed
+	stats::take();
+
+	let start = Instant::now();
+	let input = parse::<S>(input, overrides);
+	let parse_time = start.elapsed();
+	let parse_stats = stats::take();
+	let input = input?;
+
+	let parts = parts.iter()
+		.map(|&part| {
+			let start = Instant::now();
+			let answer = match part {
+				1 => S::part_1(&input),
+				_ => S::part_2(&input),
+			};
+			let time = start.elapsed();
+			PartRun { part, answer, time, stats: stats::take() }
+		})
+		.collect();
+
+	Ok(TimedRun { parse_time, parse_stats, parts })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct PartOneOnly;
+
+	impl Solution for PartOneOnly {
+		const DAY: u8 = 99;
+		const PARTS: u8 = 1;
+
+		type Input = ();
+		type Params = ();
+
+		fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+			Ok(())
+		}
+
+		fn part_1(_input: &Self::Input) -> Answer {
+			Answer::Number(1)
+		}
+
+		fn example(_n: usize) -> Option<&'static str> {
+			None
+		}
+	}
+
+	#[test]
+	fn missing_parts_have_no_answer() {
+		assert_eq!(PartOneOnly::part_2(&()), Answer::Failed("day 99 part 2 is not implemented".to_string()));
+		assert!(PartOneOnly::reference(&(), 1).is_failed());
+		assert_eq!(PartOneOnly::part_2(&()).to_string(), "no answer, day 99 part 2 is not implemented");
+	}
+}
@@ -0,0 +1,29 @@
+use std::cell::RefCell;
+
+// Statistics a solver wants to report next to its answer, such as the size of a graph it built.
+// They are collected per thread, Day::solve_timed takes the ones recorded during each phase.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stat {
+	pub name: &'static str,
+	pub value: u64,
+}
+
+thread_local! {
+	static STATS: RefCell<Vec<Stat>> = const { RefCell::new(Vec::new()) };
+}
+
+// recording the same name again replaces the previous value
+pub fn record(name: &'static str, value: u64) {
+	STATS.with_borrow_mut(|stats| {
+		match stats.iter_mut().find(|stat| stat.name == name) {
+			Some(stat) => stat.value = value,
+			None => stats.push(Stat { name, value }),
+		}
+	});
+}
+
+// the stats recorded on this thread since the last call, in recording order
+pub fn take() -> Vec<Stat> {
+	STATS.with_borrow_mut(std::mem::take)
+}
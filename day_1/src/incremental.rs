@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+
+// blocks hold about the square root of the number of IDs, and at least this many
+const MIN_BLOCK_SIZE: usize = 8;
+
+// the IDs are sorted by (ID, side), for an edit to find an ID of a given side by binary search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Side {
+	Left,
+	Right,
+}
+
+// A run of both lists merged in sorted order. Only the smallest min(left len, right len) IDs of each list
+// are paired, as with total_distance, and between two IDs the difference between the paired left IDs and
+// the paired right IDs seen so far is constant. The total distance is the area under that difference
+// (taken as positive), which the block keeps as the total width of the steps at each height relative to
+// its start
+#[derive(Debug, Default)]
+struct Block {
+	ids: Vec<(u32, Side)>,
+	num_left: usize,
+	num_right: usize,
+	paired_left: usize,
+	paired_right: usize,
+	// prefix sums of the widths and of width * height, from the lowest height up, both starting with 0
+	lowest: i64,
+	widths: Vec<i128>,
+	areas: Vec<i128>,
+	// the IDs changed since the steps were computed
+	dirty: bool,
+}
+
+impl Block {
+	fn from_ids(ids: Vec<(u32, Side)>) -> Block {
+		let num_left = ids.iter().filter(|(_id, side)| *side == Side::Left).count();
+		Block {
+			num_right: ids.len() - num_left,
+			num_left,
+			ids,
+			dirty: true,
+			..Default::default()
+		}
+	}
+
+	fn first_id(&self) -> u32 {
+		self.ids[0].0
+	}
+
+	fn last(&self) -> (u32, Side) {
+		self.ids[self.ids.len() - 1]
+	}
+
+	// `next_id` is the first ID of the next block, the last step ends there
+	fn rebuild(&mut self, next_id: Option<u32>) {
+		let (mut left_seen, mut right_seen, mut height) = (0, 0, 0);
+		let mut steps = Vec::with_capacity(self.ids.len());
+
+		for (i, &(id, side)) in self.ids.iter().enumerate() {
+			match side {
+				Side::Left => {
+					height += (left_seen < self.paired_left) as i64;
+					left_seen += 1;
+				}
+				Side::Right => {
+					height -= (right_seen < self.paired_right) as i64;
+					right_seen += 1;
+				}
+			}
+			// past the last ID, all the paired IDs have been seen and the difference is 0
+			if let Some(next_id) = self.ids.get(i + 1).map(|(id, _side)| *id).or(next_id) {
+				steps.push((height, (next_id - id) as u64));
+			}
+		}
+
+		// the heights change by at most 1 from one ID to the next, so there are few of them
+		self.lowest = steps.iter().map(|(height, _width)| *height).min().unwrap_or(0);
+		let highest = steps.iter().map(|(height, _width)| *height).max().unwrap_or(0);
+		let mut widths_at = vec![0; (highest - self.lowest + 1) as usize];
+		for (height, width) in steps {
+			widths_at[(height - self.lowest) as usize] += width as i128;
+		}
+
+		self.widths = vec![0];
+		self.areas = vec![0];
+		for (i, width) in widths_at.into_iter().enumerate() {
+			self.widths.push(self.widths[i] + width);
+			self.areas.push(self.areas[i] + width * (self.lowest + i as i64) as i128);
+		}
+		self.dirty = false;
+	}
+
+	// the area of the block, `offset` being the difference at its start
+	fn distance(&self, offset: i64) -> i128 {
+		let num_heights = self.widths.len() - 1;
+		// the heights below it are under 0 once offset
+		let split = (-offset - self.lowest).clamp(0, num_heights as i64) as usize;
+		let (width_below, area_below) = (self.widths[split], self.areas[split]);
+		let (width_above, area_above) = (self.widths[num_heights] - width_below, self.areas[num_heights] - area_below);
+
+		let offset = offset as i128;
+		(area_above + offset * width_above) - (area_below + offset * width_below)
+	}
+}
+
+// Two lists of location IDs edited one ID at a time, keeping total_distance and similarity_score up to
+// date. The similarity changes by the ID times its count in the other list, in constant time, and finding
+// an ID in the blocks is a binary search. The distance is NOT kept in logarithmic time: an edit adds 1 to
+// (or takes 1 from) the difference between the lists over a whole range of IDs, from the edited ID to
+// where the pairing of the longer list stops, and the change of the area depends on how much of that
+// range the difference is negative over, which neither a Fenwick tree nor a segment tree can count under
+// such range edits. So with n IDs in blocks of about sqrt(n), an edit rebuilds the few blocks around those
+// two IDs, in O(sqrt(n)), and adds up the areas of the others, each in constant time: O(sqrt(n)) per edit
+// rather than the O(n log n) of sorting both lists again
+#[derive(Debug)]
+pub struct IncrementalLists {
+	blocks: Vec<Block>,
+	// blocks are split past twice this and merged below half of it
+	block_size: usize,
+	left_counts: HashMap<u32, u64>,
+	right_counts: HashMap<u32, u64>,
+	num_left: usize,
+	num_right: usize,
+	distance: i128,
+	similarity: u128,
+}
+
+impl Default for IncrementalLists {
+	fn default() -> Self {
+		IncrementalLists {
+			blocks: Vec::new(),
+			block_size: MIN_BLOCK_SIZE,
+			left_counts: HashMap::new(),
+			right_counts: HashMap::new(),
+			num_left: 0,
+			num_right: 0,
+			distance: 0,
+			similarity: 0,
+		}
+	}
+}
+
+impl IncrementalLists {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	// None when the total doesn't fit in a u64, like total_distance
+	pub fn total_distance(&self) -> Option<u64> {
+		u64::try_from(self.distance).ok()
+	}
+
+	pub fn similarity_score(&self) -> u128 {
+		self.similarity
+	}
+
+	pub fn insert_left(&mut self, id: u32) {
+		self.insert(id, Side::Left);
+	}
+
+	pub fn insert_right(&mut self, id: u32) {
+		self.insert(id, Side::Right);
+	}
+
+	// false when the ID is not in the list
+	pub fn remove_left(&mut self, id: u32) -> bool {
+		self.remove(id, Side::Left)
+	}
+
+	pub fn remove_right(&mut self, id: u32) -> bool {
+		self.remove(id, Side::Right)
+	}
+
+	// (count of the ID on the side, count on the other side)
+	fn counts(&mut self, id: u32, side: Side) -> (&mut u64, u64) {
+		let (counts, other_counts) = match side {
+			Side::Left => (&mut self.left_counts, &self.right_counts),
+			Side::Right => (&mut self.right_counts, &self.left_counts),
+		};
+		(counts.entry(id).or_insert(0), other_counts.get(&id).copied().unwrap_or(0))
+	}
+
+	fn insert(&mut self, id: u32, side: Side) {
+		let (count, other_count) = self.counts(id, side);
+		*count += 1;
+		self.similarity += id as u128 * other_count as u128;
+		match side {
+			Side::Left => self.num_left += 1,
+			Side::Right => self.num_right += 1,
+		}
+
+		if self.blocks.is_empty() {
+			self.blocks.push(Block::from_ids(vec![(id, side)]));
+			self.update_distance();
+			return;
+		}
+
+		let i = self.blocks.partition_point(|block| block.last() < (id, side)).min(self.blocks.len() - 1);
+		let block = &mut self.blocks[i];
+		let position = block.ids.partition_point(|item| *item < (id, side));
+		block.ids.insert(position, (id, side));
+		match side {
+			Side::Left => block.num_left += 1,
+			Side::Right => block.num_right += 1,
+		}
+		block.dirty = true;
+
+		// the last step of the previous block ends on this block's first ID
+		if position == 0 && i > 0 {
+			self.blocks[i - 1].dirty = true;
+		}
+		self.rebalance(i);
+
+		self.update_distance();
+	}
+
+	fn remove(&mut self, id: u32, side: Side) -> bool {
+		let (count, other_count) = self.counts(id, side);
+		if *count == 0 {
+			return false;
+		}
+		*count -= 1;
+		self.similarity -= id as u128 * other_count as u128;
+		match side {
+			Side::Left => self.num_left -= 1,
+			Side::Right => self.num_right -= 1,
+		}
+
+		// the first block that ends with it or a larger one has it, as the IDs are sorted across the blocks
+		let i = self.blocks.partition_point(|block| block.last() < (id, side));
+		let position = self.blocks[i].ids.binary_search(&(id, side)).expect("A counted ID is in a block");
+
+		let block = &mut self.blocks[i];
+		block.ids.remove(position);
+		match side {
+			Side::Left => block.num_left -= 1,
+			Side::Right => block.num_right -= 1,
+		}
+		block.dirty = true;
+
+		if position == 0 && i > 0 {
+			self.blocks[i - 1].dirty = true;
+		}
+		if self.blocks[i].ids.is_empty() {
+			self.blocks.remove(i);
+		} else {
+			self.rebalance(i);
+		}
+
+		self.update_distance();
+		true
+	}
+
+	// splits block i when it grew past twice the block size, and merges it into the previous block, or the
+	// next one for the first block, when it shrank below half of it
+	fn rebalance(&mut self, i: usize) {
+		let len = self.blocks[i].ids.len();
+		if len > 2 * self.block_size {
+			let tail = self.blocks[i].ids.split_off(self.block_size);
+			self.blocks[i] = Block::from_ids(std::mem::take(&mut self.blocks[i].ids));
+			self.blocks.insert(i + 1, Block::from_ids(tail));
+		} else if 2 * len < self.block_size && self.blocks.len() > 1 {
+			let i = i.max(1);
+			let block = self.blocks.remove(i);
+			let mut ids = std::mem::take(&mut self.blocks[i - 1].ids);
+			ids.extend(block.ids);
+			self.blocks[i - 1] = Block::from_ids(ids);
+			self.rebalance(i - 1);
+		}
+	}
+
+	// cuts the IDs into new blocks once the square root of their number is more than twice the block size,
+	// or less than half of it. That sorts nothing and happens each time the number of IDs is multiplied or
+	// divided by 4, so it adds a constant time per edit
+	fn resize_blocks(&mut self) {
+		let block_size = (self.num_left + self.num_right).isqrt().max(MIN_BLOCK_SIZE);
+		if block_size <= 2 * self.block_size && 2 * block_size >= self.block_size {
+			return;
+		}
+
+		self.block_size = block_size;
+		let ids: Vec<(u32, Side)> = self.blocks.drain(..).flat_map(|block| block.ids).collect();
+		self.blocks = ids.chunks(block_size).map(|chunk| Block::from_ids(chunk.to_vec())).collect();
+	}
+
+	// rebuilds the edited blocks and the ones in which the pairing changed, and sums the areas
+	fn update_distance(&mut self) {
+		self.resize_blocks();
+		let num_paired = self.num_left.min(self.num_right);
+		let (mut left_before, mut right_before, mut offset) = (0, 0, 0);
+		let mut distance = 0;
+
+		for i in 0..self.blocks.len() {
+			let next_id = self.blocks.get(i + 1).map(Block::first_id);
+			let block = &mut self.blocks[i];
+
+			let paired_left = num_paired.saturating_sub(left_before).min(block.num_left);
+			let paired_right = num_paired.saturating_sub(right_before).min(block.num_right);
+			if block.dirty || (paired_left, paired_right) != (block.paired_left, block.paired_right) {
+				(block.paired_left, block.paired_right) = (paired_left, paired_right);
+				block.rebuild(next_id);
+			}
+
+			distance += block.distance(offset);
+			offset += paired_left as i64 - paired_right as i64;
+			left_before += block.num_left;
+			right_before += block.num_right;
+		}
+
+		self.distance = distance;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{similarity_score, total_distance};
+	use aoc_common::rng::Rng;
+
+	#[test]
+	fn follows_the_edits() {
+		let mut rng = Rng::new(11);
+		let mut lists = IncrementalLists::new();
+		let (mut first, mut second) = (Vec::new(), Vec::new());
+
+		// few distinct IDs, so that removals find them and IDs repeat across blocks
+		for _ in 0..2000 {
+			let id = rng.between(0, 200) as u32;
+			let left = rng.chance(0.5);
+			let list = if left { &mut first } else { &mut second };
+
+			if rng.chance(0.7) {
+				match left {
+					true => lists.insert_left(id),
+					false => lists.insert_right(id),
+				}
+				list.push(id);
+			} else {
+				let position = list.iter().position(|other| *other == id);
+				let removed = if left { lists.remove_left(id) } else { lists.remove_right(id) };
+				assert_eq!(removed, position.is_some());
+				if let Some(position) = position {
+					list.swap_remove(position);
+				}
+			}
+
+			assert_eq!(lists.total_distance(), total_distance(&first, &second));
+			assert_eq!(Some(lists.similarity_score()), similarity_score(&first, &second).map(u128::from));
+		}
+
+		// then empty them, for the blocks to be merged and resized down
+		while !first.is_empty() || !second.is_empty() {
+			let left = second.is_empty() || (!first.is_empty() && rng.chance(0.5));
+			let list = if left { &mut first } else { &mut second };
+			let id = list.swap_remove(rng.below(list.len()));
+			assert!(if left { lists.remove_left(id) } else { lists.remove_right(id) });
+
+			assert_eq!(lists.total_distance(), total_distance(&first, &second));
+			assert_eq!(Some(lists.similarity_score()), similarity_score(&first, &second).map(u128::from));
+		}
+	}
+}
@@ -0,0 +1,464 @@
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{lines, Line, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::iter::zip;
+
+pub mod incremental;
+pub mod report;
+
+pub fn parse_lists(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+	lines(input)
+		.map(|line| {
+			let (id_a, id_b) = line.split_once(line.text, "   ", "two location IDs separated by three spaces")?;
+			Ok((line.parse::<u32>(id_a, "a location ID")?, line.parse::<u32>(id_b, "a location ID")?))
+		})
+		.collect::<Result<Vec<_>, _>>()
+		.map(|ids| ids.into_iter().unzip())
+}
+
+// one list per column, the columns separated by any whitespace. Every line has as many location IDs as
+// the first one, at least two
+pub fn parse_columns(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+	let mut columns: Vec<Vec<u32>> = Vec::new();
+
+	for line in lines(input) {
+		let ids = line.text.split_whitespace()
+			.map(|id| line.parse::<u32>(id, "a location ID"))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		if columns.is_empty() {
+			if ids.len() < 2 {
+				return Err(line.error(line.text, "at least two location IDs separated by spaces"));
+			}
+			columns = vec![Vec::new(); ids.len()];
+		} else if ids.len() != columns.len() {
+			return Err(line.error(line.text, &format!("{} location IDs, as on the first line", columns.len())));
+		}
+
+		for (column, id) in columns.iter_mut().zip(ids) {
+			column.push(id);
+		}
+	}
+
+	Ok(columns)
+}
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"3   4\n",
+		"4   3\n",
+		"2   5\n",
+		"1   3\n",
+		"3   9\n",
+		"3   3\n",
+	)
+}
+
+// LSD radix sort, a byte per pass, linear in the number of IDs
+pub fn radix_sort(ids: &mut [u32]) {
+	let mut buffer = vec![0; ids.len()];
+
+	for shift in (0..32).step_by(8) {
+		let digit = |id: u32| ((id >> shift) & 0xff) as usize;
+
+		// the index where the IDs of each digit start
+		let mut starts = [0; 256];
+		ids.iter().for_each(|&id| starts[digit(id)] += 1);
+		let mut next = 0;
+		for start in starts.iter_mut() {
+			let count = *start;
+			*start = next;
+			next += count;
+		}
+
+		for &id in ids.iter() {
+			buffer[starts[digit(id)]] = id;
+			starts[digit(id)] += 1;
+		}
+		ids.copy_from_slice(&buffer);
+	}
+}
+
+// both lists sorted, None when the total doesn't fit in a u64
+pub fn sorted_total_distance(first: &[u32], second: &[u32]) -> Option<u64> {
+	zip(first, second)
+		.try_fold(0_u64, |total, (first_elem, second_elem)| total.checked_add(first_elem.abs_diff(*second_elem) as u64))
+}
+
+// both lists sorted, walked together one run of equal IDs at a time. None when the score doesn't fit in a u128
+pub fn sorted_similarity_score(first: &[u32], second: &[u32]) -> Option<u128> {
+	let run_length = |ids: &[u32], id: u32| ids.iter().take_while(|&&other| other == id).count();
+	let mut score: u128 = 0;
+	let (mut i, mut j) = (0, 0);
+
+	while i < first.len() {
+		let id = first[i];
+		j += second[j..].iter().take_while(|&&other| other < id).count();
+		let (first_count, second_count) = (run_length(&first[i..], id), run_length(&second[j..], id));
+
+		let run_score = (id as u128).checked_mul(first_count as u128)?.checked_mul(second_count as u128)?;
+		score = score.checked_add(run_score)?;
+		i += first_count;
+		j += second_count;
+	}
+
+	Some(score)
+}
+
+// None when the total doesn't fit in a u64
+pub fn total_distance(first: &[u32], second: &[u32]) -> Option<u64> {
+	// get sorted copies of lists without modifying original lists
+	// let first = BinaryHeap::from(first).into_sorted_vec();
+	// let second = BinaryHeap::from(second).into_sorted_vec();
+
+	let mut first = first.to_vec();
+	radix_sort(&mut first);
+	let mut second = second.to_vec();
+	radix_sort(&mut second);
+
+	sorted_total_distance(&first, &second)
+}
+
+// None when the score doesn't fit in a u64
+pub fn similarity_score(first: &[u32], second: &[u32]) -> Option<u64> {
+	let mut second_elem_count: HashMap<u32, u64> = HashMap::new();
+	second.iter().for_each(|elem| *second_elem_count.entry(*elem).or_insert(0) += 1);
+
+	first.iter()
+		.map(|&id| (id as u64).checked_mul(second_elem_count.get(&id).copied().unwrap_or(0)))
+		.try_fold(0_u64, |score, id_score| score.checked_add(id_score?))
+}
+
+// the sorted IDs, as (ID, number of times it's there)
+fn sorted_id_counts(ids: &[u32]) -> Vec<(u32, u64)> {
+	let mut ids = ids.to_vec();
+	radix_sort(&mut ids);
+
+	let mut counts: Vec<(u32, u64)> = Vec::new();
+	for id in ids {
+		match counts.last_mut() {
+			Some((last_id, count)) if *last_id == id => *count += 1,
+			_ => counts.push((id, 1)),
+		}
+	}
+	counts
+}
+
+// similarity_score where a left ID also matches the right IDs up to weights.len() - 1 away, a right ID
+// at distance k counting weights[k] times, so that weights [1] only counts exact matches like
+// similarity_score. Both lists are walked sorted, with the window of right IDs in range of the current
+// left ID only moving forward. None when the score doesn't fit in a u64
+pub fn tolerant_similarity_score(first: &[u32], second: &[u32], weights: &[u64]) -> Option<u64> {
+	assert!(!weights.is_empty(), "There has to be a weight for exact matches");
+	let tolerance = u32::try_from(weights.len() - 1).unwrap_or(u32::MAX);
+	let second = sorted_id_counts(second);
+	let mut window_start = 0;
+
+	sorted_id_counts(first).into_iter()
+		.map(|(id, count)| {
+			let (lowest, highest) = (id.saturating_sub(tolerance), id.saturating_add(tolerance));
+			window_start += second[window_start..].iter().take_while(|(other, _count)| *other < lowest).count();
+
+			let matches = second[window_start..].iter()
+				.take_while(|(other, _count)| *other <= highest)
+				.map(|(other, other_count)| weights[id.abs_diff(*other) as usize].checked_mul(*other_count))
+				.try_fold(0_u64, |total, matches| total.checked_add(matches?));
+			matches.and_then(|matches| (id as u64).checked_mul(count)?.checked_mul(matches))
+		})
+		.try_fold(0_u64, |score, id_score| score.checked_add(id_score?))
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+	Read(io::Error),
+	Parse(ParseError),
+	// names the total
+	Overflow(&'static str),
+}
+
+impl fmt::Display for StreamError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			StreamError::Read(error) => write!(f, "Could not read the lists: {}", error),
+			StreamError::Parse(error) => write!(f, "{}", error),
+			StreamError::Overflow(total) => write!(f, "The {} is too large to be computed", total),
+		}
+	}
+}
+
+impl Error for StreamError {}
+
+fn parse_pair(line: Line) -> Result<(u32, u32), ParseError> {
+	let mut ids = line.text.split_whitespace().map(|id| line.parse::<u32>(id, "a location ID"));
+	match (ids.next(), ids.next(), ids.next()) {
+		(Some(id_a), Some(id_b), None) => Ok((id_a?, id_b?)),
+		_ => Err(line.error(line.text, "two location IDs separated by spaces")),
+	}
+}
+
+// for lists too large to be held as text: reads the two lists a line at a time and sorts them in place,
+// returning (total distance, similarity score)
+pub fn stream_totals(mut reader: impl BufRead) -> Result<(u64, u128), StreamError> {
+	let (mut first, mut second) = (Vec::new(), Vec::new());
+	// reused for every line
+	let mut text = String::new();
+
+	for number in 1.. {
+		text.clear();
+		if reader.read_line(&mut text).map_err(StreamError::Read)? == 0 {
+			break;
+		}
+		let line = Line { number, text: text.trim_end_matches(['\n', '\r']) };
+		let (id_a, id_b) = parse_pair(line).map_err(StreamError::Parse)?;
+		first.push(id_a);
+		second.push(id_b);
+	}
+
+	radix_sort(&mut first);
+	radix_sort(&mut second);
+	let distance = sorted_total_distance(&first, &second).ok_or(StreamError::Overflow("total distance"))?;
+	let similarity = sorted_similarity_score(&first, &second).ok_or(StreamError::Overflow("similarity score"))?;
+	Ok((distance, similarity))
+}
+
+// total_distance or similarity_score
+pub type Metric = fn(&[u32], &[u32]) -> Option<u64>;
+
+// row i, column j compares column i to column j. None when one of them doesn't fit in a u64
+pub fn pairwise_matrix(columns: &[Vec<u32>], metric: impl Fn(&[u32], &[u32]) -> Option<u64>) -> Option<Vec<Vec<u64>>> {
+	columns.iter()
+		.map(|first| columns.iter().map(|second| metric(first, second)).collect())
+		.collect()
+}
+
+// the row of pairwise_matrix for the reference column, counting from 0
+pub fn against_reference(columns: &[Vec<u32>], reference: usize, metric: impl Fn(&[u32], &[u32]) -> Option<u64>) -> Option<Vec<u64>> {
+	columns.iter()
+		.map(|other| metric(&columns[reference], other))
+		.collect()
+}
+
+// the rows with their column numbers, under a header with the number of every column, counting from 1
+pub fn format_matrix(rows: &[(usize, Vec<u64>)]) -> String {
+	let num_columns = rows.iter().map(|(_column, values)| values.len()).max().unwrap_or(0);
+	let label_width = rows.iter().map(|(column, _values)| (column + 1).to_string().len()).max().unwrap_or(0);
+	let width = rows.iter()
+		.flat_map(|(_column, values)| values.iter().map(|value| value.to_string().len()))
+		.chain([num_columns.to_string().len()])
+		.max()
+		.unwrap_or(0);
+
+	let header = (1..=num_columns).map(|column| format!(" {:>width$}", column)).collect::<String>();
+	let lines = rows.iter().map(|(column, values)| {
+		let values = values.iter().map(|value| format!(" {:>width$}", value)).collect::<String>();
+		format!("{:>label_width$}{}", column + 1, values)
+	});
+
+	std::iter::once(format!("{:label_width$}{}", "", header))
+		.chain(lines)
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day1Params {
+	// compares every column to this one only, counting from 1, instead of every pair of columns
+	pub reference: Option<usize>,
+	// for part 2, see tolerant_similarity_score
+	pub similarity_weights: Vec<u64>,
+}
+
+impl Default for Day1Params {
+	fn default() -> Self {
+		Day1Params { reference: None, similarity_weights: vec![1] }
+	}
+}
+
+impl Params for Day1Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("reference", "the column the others are compared to, counting from 1, every pair of columns when not set"),
+		("similarity_weights", "how many times a right ID 0, 1, 2... away from a left ID counts in part 2, separated by commas"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"reference" => self.reference = match params::parse_value(name, value)? {
+				0 => return Err(format!("Invalid value for {}: {}, the columns count from 1", name, value)),
+				column => Some(column),
+			},
+			"similarity_weights" => self.similarity_weights = value.split(',')
+				.map(|weight| params::parse_value(name, weight))
+				.collect::<Result<_, _>>()?,
+			_ => return Err(params::unknown(name)),
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"reference" => self.reference.map(|column| column.to_string()),
+			"similarity_weights" => Some(self.similarity_weights.iter().map(|weight| weight.to_string()).collect::<Vec<_>>().join(",")),
+			_ => None,
+		}
+	}
+}
+
+// the puzzle's answer for two lists, a matrix for more lists or with a reference column. `name` is what the
+// metric computes, for when it doesn't fit in a u64
+fn compare_columns(columns: &[Vec<u32>], params: &Day1Params, name: &str, metric: impl Fn(&[u32], &[u32]) -> Option<u64>) -> Answer {
+	let answer = match (columns, params.reference) {
+		([first, second], None) => metric(first, second).map(Answer::from),
+		(_, None) => pairwise_matrix(columns, metric)
+			.map(|matrix| Answer::Text(format_matrix(&matrix.into_iter().enumerate().collect::<Vec<_>>()))),
+		(_, Some(reference)) => against_reference(columns, reference - 1, metric)
+			.map(|row| Answer::Text(format_matrix(&[(reference - 1, row)]))),
+	};
+	answer.unwrap_or_else(|| Answer::Failed(format!("the {} doesn't fit in a u64", name)))
+}
+
+// `size` lines, half of the right IDs are taken from the left list so that the similarity is not 0
+fn generate_lists(size: usize, rng: &mut Rng) -> String {
+	let first: Vec<i64> = (0..size).map(|_| rng.between(10_000, 99_999)).collect();
+	let second: Vec<i64> = (0..size)
+		.map(|_| if rng.chance(0.5) { *rng.choose(&first) } else { rng.between(10_000, 99_999) })
+		.collect();
+
+	zip(first, second)
+		.map(|(id_a, id_b)| format!("{}   {}\n", id_a, id_b))
+		.collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+	const DAY: u8 = 1;
+
+	type Input = (Vec<Vec<u32>>, Day1Params);
+	type Params = Day1Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Default::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		let columns = parse_columns(input)?;
+		if let Some(reference) = params.reference.filter(|reference| *reference > columns.len()) {
+			let expected = format!("at least {} location IDs, for the reference column", reference);
+			return Err(match lines(input).next() {
+				Some(line) => line.error(line.text, &expected),
+				None => ParseError::at_end(input, &expected),
+			});
+		}
+		Ok((columns, params.clone()))
+	}
+
+	fn part_1((columns, params): &Self::Input) -> Answer {
+		compare_columns(columns, params, "total distance", total_distance)
+	}
+
+	fn part_2((columns, params): &Self::Input) -> Answer {
+		compare_columns(columns, params, "similarity score", |first, second| tolerant_similarity_score(first, second, &params.similarity_weights))
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_lists(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day1::parse(test_input_1()).unwrap();
+		assert_eq!(Day1::part_1(&input), Answer::Number(11));
+		assert_eq!(Day1::part_2(&input), Answer::Number(31));
+	}
+
+	#[test]
+	fn streaming() {
+		assert_eq!(stream_totals(test_input_1().as_bytes()).unwrap(), (11, 31));
+
+		let error = stream_totals("3   4\n4 3 5\n".as_bytes()).unwrap_err();
+		assert!(matches!(error, StreamError::Parse(ParseError { line: 2, .. })));
+	}
+
+	#[test]
+	fn radix_sort_and_merged_similarity() {
+		let (mut first, mut second) = parse_lists(&generate_lists(500, &mut Rng::new(3))).unwrap();
+		let similarity = similarity_score(&first, &second).unwrap();
+
+		let mut sorted = first.clone();
+		sorted.sort_unstable();
+		radix_sort(&mut first);
+		radix_sort(&mut second);
+		assert_eq!(first, sorted);
+		assert_eq!(sorted_similarity_score(&first, &second), Some(similarity as u128));
+	}
+
+	#[test]
+	fn tolerant_similarity() {
+		let (first, second) = parse_lists(test_input_1()).unwrap();
+		assert_eq!(tolerant_similarity_score(&first, &second, &[1]), Some(31));
+		// 3 matches the 4 and the three 3s, 4 the three 3s, the 4 and the 5, 2 the three 3s and 1 nothing
+		assert_eq!(tolerant_similarity_score(&first, &second, &[1, 1]), Some(3 * 4 * 3 + 4 * 5 + 2 * 3));
+
+		let mut rng = Rng::new(5);
+		let mut ids = |size| (0..size).map(|_| rng.between(0, 30) as u32).collect::<Vec<_>>();
+		let (first, second) = (ids(50), ids(40));
+		let weights = [5, 3, 0, 1];
+		let expected: u64 = first.iter()
+			.flat_map(|id| second.iter().map(move |other| (id, id.abs_diff(*other) as usize)))
+			.filter(|(_id, distance)| *distance < weights.len())
+			.map(|(id, distance)| *id as u64 * weights[distance])
+			.sum();
+		assert_eq!(tolerant_similarity_score(&first, &second, &weights), Some(expected));
+		assert_eq!(tolerant_similarity_score(&first, &second, &[1]), similarity_score(&first, &second));
+	}
+
+	#[test]
+	fn three_columns() {
+		let input = concat!(
+			"3 4 3\n",
+			"4 3 1\n",
+			"2 5 2\n",
+		);
+		let (columns, _params) = Day1::parse(input).unwrap();
+		assert_eq!(pairwise_matrix(&columns, total_distance), Some(vec![vec![0, 3, 3], vec![3, 0, 6], vec![3, 6, 0]]));
+		assert_eq!(against_reference(&columns, 0, similarity_score), Some(vec![9, 7, 5]));
+
+		let distances = Day1::part_1(&(columns.clone(), Day1Params::default()));
+		assert_eq!(distances, Answer::Text("  1 2 3\n1 0 3 3\n2 3 0 6\n3 3 6 0".to_string()));
+		let similarities = Day1::part_2(&(columns, Day1Params { reference: Some(2), ..Default::default() }));
+		assert_eq!(similarities, Answer::Text("   1  2  3\n2  7 12  3".to_string()));
+	}
+
+	#[test]
+	fn totals_too_large() {
+		// 70000 * 70000 matches of the largest ID
+		let ids = vec![u32::MAX; 70_000];
+		assert_eq!(similarity_score(&ids, &ids), None);
+		assert_eq!(sorted_similarity_score(&ids, &ids), Some(u32::MAX as u128 * 70_000 * 70_000));
+
+		let columns = vec![vec![1, 2], vec![3, 4]];
+		let answer = compare_columns(&columns, &Day1Params::default(), "total distance", |_first, _second| None);
+		assert_eq!(answer, Answer::Failed("the total distance doesn't fit in a u64".to_string()));
+
+		let params = Day1Params { similarity_weights: vec![u64::MAX], ..Default::default() };
+		let input = Day1::parse_with(test_input_1(), &params).unwrap();
+		assert_eq!(Day1::part_2(&input), Answer::Failed("the similarity score doesn't fit in a u64".to_string()));
+	}
+}
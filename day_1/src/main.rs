@@ -1,49 +1,106 @@
-use std::collections::HashMap;
-use std::fs;
-use std::iter::zip;
-
-fn read_lists_file(file_name: &str) -> (Vec<u32>, Vec<u32>) {
-	let file_path = format!("../{}", file_name);
-
-	fs::read_to_string(file_path).unwrap().lines()
-		.map(|line| line.split("   ").collect::<Vec<&str>>())
-		.map(|vec_ids| (vec_ids[0], vec_ids[1]))
-		.map(|(id_a, id_b)| (id_a.parse::<u32>().unwrap(), id_b.parse::<u32>().unwrap()))
-		.unzip()
+use aoc_common::input::{resolve_input, resolve_input_source, MainError};
+use aoc_common::log;
+use day_1::report::DistanceReport;
+use day_1::{against_reference, format_matrix, pairwise_matrix, parse_columns, similarity_score, stream_totals, total_distance, Metric};
+use std::env;
+use std::io;
+
+// the largest distances shown by --report, unless --top is given
+const DEFAULT_TOP: usize = 10;
+
+// removes `flag` from the arguments, true if it was there
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+	match args.iter().position(|arg| arg == flag) {
+		Some(i) => {
+			args.remove(i);
+			true
+		}
+		None => false,
+	}
 }
 
-#[allow(dead_code)]
-fn test_lists()  -> (Vec<u32>, Vec<u32>) {
-	(vec![3, 4, 2, 1, 3, 3],
-	 vec![4, 3, 5, 3, 9, 3])
+// removes `flag` and the value after it from the arguments, an error when the value is missing
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, io::Error> {
+	let Some(i) = args.iter().position(|arg| arg == flag) else {
+		return Ok(None);
+	};
+	args.remove(i);
+	match i < args.len() {
+		true => Ok(Some(args.remove(i))),
+		false => Err(io::Error::other(format!("Missing value for {}", flag))),
+	}
 }
 
-fn total_distance(first: &Vec<u32>, second: &Vec<u32>) -> u32 {
-	// get sorted copies of lists without modifying original lists
-	// let first = BinaryHeap::from(first).into_sorted_vec();
-	// let second = BinaryHeap::from(second).into_sorted_vec();
+// the total of a metric, an error naming it when it doesn't fit in a u64
+fn fits<T>(total: Option<T>, name: &str) -> Result<T, io::Error> {
+	total.ok_or_else(|| io::Error::other(format!("The {} doesn't fit in a u64", name)))
+}
 
-	let mut first = first.clone();
-	first.sort_unstable();
-	let mut second = second.clone();
-	second.sort_unstable();
+fn main() -> Result<(), MainError> {
+	let mut args = log::apply_verbosity_flags(env::args().skip(1));
+	// --stream reads two lists a line at a time, for lists too large to be read at once
+	let stream = take_flag(&mut args, "--stream");
+	// --report details the distances of every pair of lists, with the --top N largest
+	let report = take_flag(&mut args, "--report");
+	let top = match take_value(&mut args, "--top")? {
+		Some(top) => top.parse().map_err(|_| io::Error::other(format!("Invalid value for --top: {}", top)))?,
+		None => DEFAULT_TOP,
+	};
+	// --reference N compares every column to column N only, counting from 1, like --param reference=N of the runner
+	let reference = match take_value(&mut args, "--reference")? {
+		Some(reference) => match reference.parse::<usize>() {
+			Ok(column) if column > 0 => Some(column - 1),
+			_ => return Err(io::Error::other(format!("Invalid value for --reference: {}, the columns count from 1", reference)).into()),
+		},
+		None => None,
+	};
+	let input_arg = args.first().map(String::as_str);
 
-	zip(first, second)
-		.map(|(first_elem, second_elem)| first_elem.abs_diff(second_elem))
-		.sum()
-}
+	if stream && reference.is_some() {
+		return Err(io::Error::other("--stream reads two lists, it can't be used with --reference").into());
+	}
+	if stream {
+		let (distance, similarity) = stream_totals(resolve_input_source(input_arg, 1)?.open()?)?;
+		println!("Total distance: {}", distance);
+		println!("Similarity score: {}", similarity);
+		return Ok(());
+	}
 
-fn similarity_score(first: &Vec<u32>, second: &Vec<u32>) -> u32 {
-	let mut second_elem_count: HashMap<u32, u32> = HashMap::new();
-	second.iter().for_each(|elem| *second_elem_count.entry(*elem).or_insert(0) += 1);
-	
-	first.iter()
-		.map(|&id| id * *second_elem_count.entry(id).or_insert(0))
-		.sum()
-}
+	let columns = parse_columns(&resolve_input(input_arg, 1)?)?;
+	if let Some(reference) = reference.filter(|reference| *reference >= columns.len()) {
+		return Err(io::Error::other(format!("There is no column {} to use as the reference, only {}", reference + 1, columns.len())).into());
+	}
+
+	if report {
+		for (i, first) in columns.iter().enumerate() {
+			for (j, second) in columns.iter().enumerate().skip(i + 1) {
+				if reference.is_some_and(|reference| reference != i && reference != j) {
+					continue;
+				}
+				if columns.len() > 2 {
+					println!("Columns {} and {}:", i + 1, j + 1);
+				}
+				println!("{}", DistanceReport::new(first, second).format(top));
+			}
+		}
+		return Ok(());
+	}
+
+	if let Some(reference) = reference {
+		let row = |metric: Metric, name| fits(against_reference(&columns, reference, metric), name).map(|row| [(reference, row)]);
+		println!("Total distances:\n{}", format_matrix(&row(total_distance, "total distance")?));
+		println!("Similarity scores:\n{}", format_matrix(&row(similarity_score, "similarity score")?));
+		return Ok(());
+	}
+
+	if let [first, second] = columns.as_slice() {
+		println!("Total distance: {}", fits(total_distance(first, second), "total distance")?);
+		println!("Similarity score: {}", fits(similarity_score(first, second), "similarity score")?);
+		return Ok(());
+	}
 
-fn main() {
-	let (first, second) = read_lists_file("input.txt");
-	println!("Total distance: {}", total_distance(&first, &second));
-	println!("Similarity score: {}", similarity_score(&first, &second))
+	let rows = |metric: Metric, name| fits(pairwise_matrix(&columns, metric), name).map(|matrix| matrix.into_iter().enumerate().collect::<Vec<_>>());
+	println!("Total distances:\n{}", format_matrix(&rows(total_distance, "total distance")?));
+	println!("Similarity scores:\n{}", format_matrix(&rows(similarity_score, "similarity score")?));
+	Ok(())
 }
@@ -0,0 +1,132 @@
+use crate::radix_sort;
+use std::collections::BTreeMap;
+use std::iter::zip;
+
+// What total_distance adds up, for the reviewers of two lists that disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceReport {
+	// (left ID, right ID, distance) of the sorted pairs, in order
+	pub pairs: Vec<(u32, u32, u32)>,
+	// the largest IDs of the longer list, which have no pair
+	pub unpaired: Vec<u32>,
+	// (ID, number of times it is in the list) of the IDs that are not in the other list, by ID
+	pub only_left: Vec<(u32, usize)>,
+	pub only_right: Vec<(u32, usize)>,
+}
+
+fn id_counts(ids: &[u32]) -> BTreeMap<u32, usize> {
+	let mut counts = BTreeMap::new();
+	ids.iter().for_each(|id| *counts.entry(*id).or_insert(0) += 1);
+	counts
+}
+
+// the IDs of `counts` that `other` doesn't have
+fn missing_from(counts: &BTreeMap<u32, usize>, other: &BTreeMap<u32, usize>) -> Vec<(u32, usize)> {
+	counts.iter()
+		.filter(|(id, _count)| !other.contains_key(id))
+		.map(|(id, count)| (*id, *count))
+		.collect()
+}
+
+impl DistanceReport {
+	pub fn new(first: &[u32], second: &[u32]) -> Self {
+		let mut first_sorted = first.to_vec();
+		radix_sort(&mut first_sorted);
+		let mut second_sorted = second.to_vec();
+		radix_sort(&mut second_sorted);
+
+		let pairs = zip(&first_sorted, &second_sorted)
+			.map(|(first_elem, second_elem)| (*first_elem, *second_elem, first_elem.abs_diff(*second_elem)))
+			.collect::<Vec<_>>();
+		let longer = if first_sorted.len() > second_sorted.len() { &first_sorted } else { &second_sorted };
+
+		let (first_counts, second_counts) = (id_counts(first), id_counts(second));
+		DistanceReport {
+			unpaired: longer[pairs.len()..].to_vec(),
+			pairs,
+			only_left: missing_from(&first_counts, &second_counts),
+			only_right: missing_from(&second_counts, &first_counts),
+		}
+	}
+
+	pub fn total_distance(&self) -> u64 {
+		self.pairs.iter().map(|(_first, _second, distance)| *distance as u64).sum()
+	}
+
+	// the `k` pairs the furthest apart, furthest first, in pair order when the distances are equal
+	pub fn largest(&self, k: usize) -> Vec<(u32, u32, u32)> {
+		let mut pairs = self.pairs.clone();
+		pairs.sort_by_key(|(_first, _second, distance)| std::cmp::Reverse(*distance));
+		pairs.truncate(k);
+		pairs
+	}
+
+	// (lowest distance, highest distance, number of pairs) with the distances grouped by powers of two: 0,
+	// 1, 2 to 3, 4 to 7... up to the largest distance
+	pub fn histogram(&self) -> Vec<(u64, u64, usize)> {
+		let bucket = |distance: u32| (u32::BITS - distance.leading_zeros()) as usize;
+		let num_buckets = self.pairs.iter().map(|(_first, _second, distance)| bucket(*distance) + 1).max().unwrap_or(0);
+
+		let mut counts = vec![0; num_buckets];
+		self.pairs.iter().for_each(|(_first, _second, distance)| counts[bucket(*distance)] += 1);
+		counts.into_iter().enumerate()
+			.map(|(i, count)| match i {
+				0 => (0, 0, count),
+				_ => (1 << (i - 1), (1 << i) - 1, count),
+			})
+			.collect()
+	}
+
+	// every section, with the `top` largest distances
+	pub fn format(&self, top: usize) -> String {
+		let join = |ids: Vec<String>| match ids.is_empty() {
+			true => "none".to_string(),
+			false => ids.join(", "),
+		};
+		let histogram = self.histogram().into_iter()
+			.map(|(lowest, highest, count)| match lowest == highest {
+				true => (lowest.to_string(), count),
+				false => (format!("{}-{}", lowest, highest), count),
+			})
+			.collect::<Vec<_>>();
+		let width = histogram.iter().map(|(range, _count)| range.len()).max().unwrap_or(0);
+
+		let mut lines = vec![format!("Total distance: {}", self.total_distance()), "Pairs (left, right, distance):".to_string()];
+		lines.extend(self.pairs.iter().map(|(first, second, distance)| format!("  {} {} {}", first, second, distance)));
+		if !self.unpaired.is_empty() {
+			lines.push(format!("Unpaired IDs of the longer list: {}", join(self.unpaired.iter().map(u32::to_string).collect())));
+		}
+
+		lines.push("Largest distances:".to_string());
+		lines.extend(self.largest(top).into_iter().map(|(first, second, distance)| format!("  {} between {} and {}", distance, first, second)));
+		lines.push("Distances:".to_string());
+		lines.extend(histogram.into_iter().map(|(range, count)| format!("  {:>width$}: {}", range, count)));
+
+		let counts = |ids: &[(u32, usize)]| join(ids.iter().map(|(id, count)| format!("{} x{}", id, count)).collect());
+		lines.push(format!("Only in the left list: {}", counts(&self.only_left)));
+		lines.push(format!("Only in the right list: {}", counts(&self.only_right)));
+		lines.join("\n")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{parse_lists, test_input_1};
+
+	#[test]
+	fn report() {
+		let (first, second) = parse_lists(test_input_1()).unwrap();
+		let report = DistanceReport::new(&first, &second);
+
+		assert_eq!(report.pairs, vec![(1, 3, 2), (2, 3, 1), (3, 3, 0), (3, 4, 1), (3, 5, 2), (4, 9, 5)]);
+		assert_eq!(report.total_distance(), 11);
+		assert_eq!(report.largest(2), vec![(4, 9, 5), (1, 3, 2)]);
+		assert_eq!(report.histogram(), vec![(0, 0, 1), (1, 1, 2), (2, 3, 2), (4, 7, 1)]);
+		assert_eq!((report.only_left, report.only_right), (vec![(1, 1), (2, 1)], vec![(5, 1), (9, 1)]));
+
+		let report = DistanceReport::new(&[7, 1, 7], &[2]);
+		assert_eq!((report.pairs, report.unpaired), (vec![(1, 2, 1)], vec![7, 7]));
+		assert_eq!(report.only_left, vec![(1, 1), (7, 2)]);
+	}
+}
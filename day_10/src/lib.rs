@@ -0,0 +1,203 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::Pos;
+use aoc_common::grid::Grid;
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{first_problem, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"0123\n",
+		"1234\n",
+		"8765\n",
+		"9876\n"
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"89010123\n",
+		"78121874\n",
+		"87430965\n",
+		"96549874\n",
+		"45678903\n",
+		"32019012\n",
+		"01329801\n",
+		"10456732\n"
+	)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+pub fn validate_input(input: &str) -> Result<Grid<u8>, Vec<ParseError>> {
+	Grid::validate(input, "a height digit", |c| c.to_digit(10).map(|height| height as u8))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day10Params {
+	// the height trails end at
+	pub top_height: u8,
+}
+
+impl Default for Day10Params {
+	fn default() -> Self {
+		Day10Params { top_height: 9 }
+	}
+}
+
+impl Params for Day10Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("top_height", "the height trails end at"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"top_height" => self.top_height = params::parse_value(name, value)?,
+			_ => return Err(params::unknown(name)),
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"top_height" => Some(self.top_height.to_string()),
+			_ => None,
+		}
+	}
+}
+
+// the sum, over the trailheads, of the tops reachable from them
+pub fn sum_trailhead_scores(map: &Grid<u8>, top_height: u8) -> usize {
+	map.find_all(&0)
+		.map(|trailhead_start| get_trailhead_end_positions(trailhead_start, map, top_height))
+		.map(|trailhead_ends| trailhead_ends.len())
+		.sum()
+}
+
+fn get_trailhead_end_positions(curr_pos: Pos, map: &Grid<u8>, top_height: u8) -> HashSet<Pos> {
+	let current_height = map[curr_pos];
+	if current_height == top_height {
+		return HashSet::from([curr_pos]);
+	}
+
+	map.neighbours_4(curr_pos)
+		.filter(|new_pos| map[*new_pos] == current_height + 1)
+		.flat_map(|new_pos| get_trailhead_end_positions(new_pos, map, top_height))
+		.collect()
+}
+
+// the sum, over the trailheads, of the distinct trails from them to a top
+pub fn sum_trailhead_ratings(map: &Grid<u8>, top_height: u8) -> usize {
+	map.find_all(&0)
+		.map(|trailhead_start| get_trailhead_rating(trailhead_start, map, top_height))
+		.sum()
+}
+
+fn get_trailhead_rating(curr_pos: Pos, map: &Grid<u8>, top_height: u8) -> usize {
+	let current_height = map[curr_pos];
+	if current_height == top_height {
+		return 1;
+	}
+
+	map.neighbours_4(curr_pos)
+		.filter(|new_pos| map[*new_pos] == current_height + 1)
+		.map(|new_pos| get_trailhead_rating(new_pos, map, top_height))
+		.sum()
+}
+
+// a `size` by `size` map of random heights, with trails walked over it from random 0s. A trail stops early when it walks into itself
+fn generate_topographic_map(size: usize, rng: &mut Rng) -> String {
+	let size = size.max(1) as i32;
+	let mut map = Grid::new(size, size, 0);
+	for pos in map.positions().collect::<Vec<_>>() {
+		map[pos] = rng.below(10);
+	}
+
+	for _ in 0..(size * size / 20).max(1) {
+		let mut trail = vec![Pos::new(rng.below(size as usize) as i32, rng.below(size as usize) as i32)];
+		while trail.len() < 10 {
+			let neighbours: Vec<Pos> = map.neighbours_4(trail[trail.len() - 1])
+				.filter(|pos| !trail.contains(pos))
+				.collect();
+			let Some(&pos) = neighbours.get(rng.below(neighbours.len().max(1))) else { break };
+			trail.push(pos);
+		}
+
+		for (height, pos) in trail.into_iter().enumerate() {
+			map[pos] = height;
+		}
+	}
+
+	map.to_string()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+	const DAY: u8 = 10;
+
+	type Input = (Grid<u8>, Day10Params);
+	type Params = Day10Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Day10Params::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Ok((parse_input(input)?, params.clone()))
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1((map, params): &Self::Input) -> Answer {
+		sum_trailhead_scores(map, params.top_height).into()
+	}
+
+	fn part_2((map, params): &Self::Input) -> Answer {
+		sum_trailhead_ratings(map, params.top_height).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_topographic_map(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day10::parse(test_input_1()).unwrap();
+		assert_eq!(Day10::part_1(&input), Answer::Number(1));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day10::parse(test_input_2()).unwrap();
+		assert_eq!(Day10::part_1(&input), Answer::Number(36));
+		assert_eq!(Day10::part_2(&input), Answer::Number(81));
+	}
+
+	#[test]
+	fn lower_top_height() {
+		let input = Day10::parse_with(test_input_1(), &Day10Params { top_height: 2 }).unwrap();
+		assert_eq!(Day10::part_1(&input), Answer::Number(2));
+		assert_eq!(Day10::part_2(&input), Answer::Number(3));
+	}
+}
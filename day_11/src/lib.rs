@@ -0,0 +1,218 @@
+#![allow(dead_code)]
+
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{lines, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::stats;
+use std::collections::HashMap;
+
+fn test_input_1() -> &'static str {
+	"0 1 10 99 999"
+}
+
+fn test_input_2() -> &'static str {
+	"125 17"
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day11Params {
+	pub blinks_part_1: u32,
+	pub blinks_part_2: u32,
+}
+
+impl Default for Day11Params {
+	fn default() -> Self {
+		Day11Params { blinks_part_1: 25, blinks_part_2: 75 }
+	}
+}
+
+impl Params for Day11Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("blinks_part_1", "the number of blinks of part 1"),
+		("blinks_part_2", "the number of blinks of part 2"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"blinks_part_1" => self.blinks_part_1 = params::parse_value(name, value)?,
+			"blinks_part_2" => self.blinks_part_2 = params::parse_value(name, value)?,
+			_ => return Err(params::unknown(name)),
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"blinks_part_1" => Some(self.blinks_part_1.to_string()),
+			"blinks_part_2" => Some(self.blinks_part_2.to_string()),
+			_ => None,
+		}
+	}
+}
+
+// the number of stones with each engraved number
+pub fn parse_input(input: &str) -> Result<HashMap<u64, u64>, ParseError> {
+	lines(input)
+		.flat_map(|line| line.text.split_whitespace().map(move |num| line.parse::<u64>(num, "a stone number")))
+		.try_fold(HashMap::new(), |mut stone_counts, stone| {
+			*stone_counts.entry(stone?).or_insert(0) += 1;
+			Ok(stone_counts)
+		})
+}
+
+
+fn digit_count(mut num: u64) -> u32 {
+	let mut digit_count = 0;
+	while num != 0 {
+		num /= 10;
+		digit_count += 1;
+	}
+	digit_count
+}
+
+fn split_if_digit_count_even(num: u64) -> Option<(u64, u64)> {
+	let digit_count = digit_count(num);
+	if digit_count.is_multiple_of(2) {
+		let mask = 10_u64.pow(digit_count / 2);
+		let right_split = num % mask;
+		let left_split = num / mask;
+		Some((left_split, right_split))
+	} else {
+		None
+	}
+}
+
+fn get_stone_blink_result(stone: u64) -> (u64, Option<u64>) {
+	if stone == 0 {
+		return (1, None);
+	}
+
+	if let Some(stone_split) = split_if_digit_count_even(stone) {
+		(stone_split.0, Some(stone_split.1))
+	} else {
+		(stone * 2024, None)
+	}
+}
+
+// the order of the stones doesn't matter, so stones with the same number are counted together
+pub fn blink_times(mut stones: HashMap<u64, u64>, blink_count: u32) -> HashMap<u64, u64> {
+	let mut blink_result = HashMap::new();
+
+	for _ in 0..blink_count {
+		for (stone, stone_count) in stones.iter() {
+			let new_stones = get_stone_blink_result(*stone);
+
+			*blink_result.entry(new_stones.0).or_insert(0) += stone_count;
+			if let Some(new_stone_1) = new_stones.1 {
+				*blink_result.entry(new_stone_1).or_insert(0) += stone_count;
+			}
+		}
+
+		stones = blink_result.clone();
+		blink_result.clear()
+	}
+
+	stats::record("distinct stones", stones.len() as u64);
+	stones
+}
+
+// reference for blink_times: every stone on its own, in a list, as in the puzzle
+pub fn count_stones_one_by_one(stones: &HashMap<u64, u64>, blink_count: u32) -> usize {
+	let mut stones: Vec<u64> = stones.iter()
+		.flat_map(|(stone, stone_count)| std::iter::repeat_n(*stone, *stone_count as usize))
+		.collect();
+
+	for _ in 0..blink_count {
+		stones = stones.into_iter()
+			.flat_map(|stone| {
+				let (first, second) = get_stone_blink_result(stone);
+				std::iter::once(first).chain(second)
+			})
+			.collect();
+	}
+
+	stones.len()
+}
+
+// `size` stones of up to 7 digits
+fn generate_stones(size: usize, rng: &mut Rng) -> String {
+	(0..size)
+		.map(|_| {
+			let num_digits = rng.between(1, 7) as u32;
+			rng.below(10_usize.pow(num_digits)).to_string()
+		})
+		.collect::<Vec<_>>()
+		.join(" ") + "\n"
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+	const DAY: u8 = 11;
+	const REFERENCE_PARTS: &'static [u8] = &[1];
+
+	type Input = (HashMap<u64, u64>, Day11Params);
+	type Params = Day11Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Day11Params::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Ok((parse_input(input)?, params.clone()))
+	}
+
+	fn part_1((stones, params): &Self::Input) -> Answer {
+		blink_times(stones.clone(), params.blinks_part_1).values().sum::<u64>().into()
+	}
+
+	fn part_2((stones, params): &Self::Input) -> Answer {
+		blink_times(stones.clone(), params.blinks_part_2).values().sum::<u64>().into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_stones(size, rng))
+	}
+
+	fn reference((stones, params): &Self::Input, _part: u8) -> Answer {
+		count_stones_one_by_one(stones, params.blinks_part_1).into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aoc_common::diff::find_divergence;
+
+	#[test]
+	fn example_1_one_blink() {
+		let input = Day11::parse_with(test_input_1(), &Day11Params { blinks_part_1: 1, ..Default::default() }).unwrap();
+		assert_eq!(Day11::part_1(&input), Answer::Number(7));
+	}
+
+	#[test]
+	fn example_2_six_blinks() {
+		let input = Day11::parse_with(test_input_2(), &Day11Params { blinks_part_1: 6, ..Default::default() }).unwrap();
+		assert_eq!(Day11::part_1(&input), Answer::Number(22));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day11::parse(test_input_2()).unwrap();
+		assert_eq!(Day11::part_1(&input), Answer::Number(55312));
+	}
+
+	#[test]
+	fn reference_part_1() {
+		assert_eq!(find_divergence::<Day11>(1, 3, 4), Ok(None));
+	}
+}
@@ -0,0 +1,308 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::{Dir, Pos};
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::render::{region_colour, Image};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::{HashSet, VecDeque};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"AAAA\n",
+		"BBCD\n",
+		"BBCC\n",
+		"EEEC\n"
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"OOOOO\n",
+		"OXOXO\n",
+		"OOOOO\n",
+		"OXOXO\n",
+		"OOOOO\n"
+	)
+}
+
+fn test_input_3() -> &'static str {
+	concat!(
+		"RRRRIICCFF\n",
+		"RRRRIICCCF\n",
+		"VVRRRCCFFF\n",
+		"VVRCCCJFFF\n",
+		"VVVVCJJCFE\n",
+		"VVIVCCJJEE\n",
+		"VVIIICJJEE\n",
+		"MIIIIIJJEE\n",
+		"MIIISIJEEE\n",
+		"MMMISSJEEE\n"
+	)
+}
+
+fn test_input_4() -> &'static str {
+	concat!(
+		"EEEEE\n",
+		"EXXXX\n",
+		"EEEEE\n",
+		"EXXXX\n",
+		"EEEEE\n"
+	)
+}
+
+fn test_input_5() -> &'static str {
+	concat!(
+		"AAAAAA\n",
+		"AAABBA\n",
+		"AAABBA\n",
+		"ABBAAA\n",
+		"ABBAAA\n",
+		"AAAAAA\n"
+	)
+}
+
+// the sum of the area times the perimeter of every region
+pub fn get_fence_price(map: &Grid<char>) -> u32 {
+	let mut visited_pos = HashSet::new();
+
+	map.iter()
+		.map(|(pos, plant_type)| get_plot_area_perimeter(pos, *plant_type, map, &mut visited_pos))
+		.map(|(area, perimeter)| area * perimeter)
+		.sum()
+}
+
+fn get_plot_area_perimeter(pos: Pos, plant_type: char, map: &Grid<char>, visited_pos: &mut HashSet<Pos>) -> (u32, u32) { // (area, perimeter)
+	// out of bounds or a different plant
+	if map.get(pos) != Some(&plant_type) {
+		return (0, 1)
+	}
+
+	if visited_pos.contains(&pos) {
+		return (0, 0)
+	}
+
+	visited_pos.insert(pos);
+
+	Dir::ALL.iter()
+		.map(|dir| pos.step(*dir))
+		.map(|new_pos| get_plot_area_perimeter(new_pos, plant_type, map, visited_pos))
+		.fold((1, 0), |acc, new_pos| (acc.0 + new_pos.0, acc.1 + new_pos.1))
+}
+
+fn get_surrounding_pos_is_plant_type(pos: Pos, plant_type: char, map: &Grid<char>) -> Vec<char> {
+	[
+		(-1, -1), (-1, 0), (-1, 1),
+		(0, -1), (0, 0), (0, 1), // don't actually need the (0, 0), but it makes it easier to visualize
+		(1, -1), (1, 0), (1, 1)
+	].iter()
+		.map(|&pos_delta| pos + Pos::from(pos_delta))
+		.map(|pos| {
+			if map.get(pos) == Some(&plant_type) {
+				's' // same plant
+			} else {
+				'd' // different plant or out of bounds
+			}
+		})
+		.collect()
+}
+
+fn pos_corner_count(pos: Pos, plant_type: char, map: &Grid<char>) -> u32 {
+	let surrounding_pos = get_surrounding_pos_is_plant_type(pos, plant_type, map);
+
+	[
+		[
+			'-', 'd', '-',
+			'd', 's', '-',
+			'-', '-', '-',
+		],
+		[
+			'-', 'd', '-',
+			'-', 's', 'd',
+			'-', '-', '-'
+		],
+		[
+			'-', '-', '-',
+			'-', 's', 'd',
+			'-', 'd', '-'
+		],
+		[
+			'-', '-', '-',
+			'd', 's', '-',
+			'-', 'd', '-'
+		],
+		[
+			'd', 's', '-',
+			's', 's', '-',
+			'-', '-', '-'
+		],
+		[
+			'-', 's', 'd',
+			'-', 's', 's',
+			'-', '-', '-'
+		],
+		[
+			'-', '-', '-',
+			'-', 's', 's',
+			'-', 's', 'd'
+		],
+		[
+			'-', '-', '-',
+			's', 's', '-',
+			'd', 's', '-'
+		]
+	].iter()
+		.filter(|corner_case| corner_case.iter()
+			.zip(&surrounding_pos)
+			.all(|(&type_corner, type_pos)| {
+				if type_corner == '-' { return true }
+				type_corner == *type_pos
+			})
+		)
+		.count() as u32
+}
+
+// the sum of the area times the number of sides of every region
+pub fn get_discounted_fence_price(map: &Grid<char>) -> u32 {
+	let mut visited_pos = HashSet::new();
+
+	map.iter()
+		.map(|(pos, plant_type)| get_plot_area_sides(pos, *plant_type, map, &mut visited_pos))
+		.map(|(area, num_sides)| area * num_sides)
+		.sum()
+}
+
+fn get_plot_area_sides(pos: Pos, plant_type: char, map: &Grid<char>, visited_pos: &mut HashSet<Pos>) -> (u32, u32) { // (area, num sides)
+	// out of bounds or a different plant
+	if map.get(pos) != Some(&plant_type) {
+		return (0, 0)
+	}
+
+	if visited_pos.contains(&pos) {
+		return (0, 0)
+	}
+
+	visited_pos.insert(pos);
+
+	let num_sides_delta = pos_corner_count(pos, plant_type, map);
+
+	Dir::ALL.iter()
+		.map(|dir| pos.step(*dir))
+		.map(|new_pos| get_plot_area_sides(new_pos, plant_type, map, visited_pos))
+		.fold((1, num_sides_delta), |acc, new_pos| (acc.0 + new_pos.0, acc.1 + new_pos.1))
+}
+
+// one colour per plant type, two touching regions never have the same type
+fn render_regions(map: &Grid<char>) -> Image {
+	Image::from_grid(map, |plant_type| region_colour(*plant_type as usize))
+}
+
+// a `size` by `size` garden: regions grown from random seeds, sprinkled with single plants that make
+// holes in them
+fn generate_garden(size: usize, rng: &mut Rng) -> String {
+	let size = size.max(1) as i32;
+	let mut map: Grid<Option<char>> = Grid::new(size, size, None);
+	let mut queue = VecDeque::new();
+
+	for _ in 0..(size * size / 20).max(1) {
+		let pos = Pos::new(rng.below(size as usize) as i32, rng.below(size as usize) as i32);
+		map[pos] = Some((b'A' + rng.below(26) as u8) as char);
+		queue.push_back(pos);
+	}
+
+	while let Some(pos) = queue.pop_front() {
+		for neighbour in map.neighbours_4(pos).collect::<Vec<_>>() {
+			if map[neighbour].is_none() {
+				map[neighbour] = map[pos];
+				queue.push_back(neighbour);
+			}
+		}
+	}
+
+	map.map(|plant| if rng.chance(0.03) { (b'A' + rng.below(26) as u8) as char } else { plant.unwrap() })
+		.to_string()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+	const DAY: u8 = 12;
+
+	type Input = Grid<char>;
+	type Params = ();
+
+	// every character is a plant type
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Grid::parse_chars(input)
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		Grid::validate(input, "any character", Some).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		get_fence_price(input).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		get_discounted_fence_price(input).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			3 => Some(test_input_3()),
+			4 => Some(test_input_4()),
+			5 => Some(test_input_5()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_garden(size, rng))
+	}
+
+	fn render(input: &Self::Input) -> Vec<(&'static str, Image)> {
+		vec![("regions", render_regions(input))]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day12::parse(test_input_1()).unwrap();
+		assert_eq!(Day12::part_1(&input), Answer::Number(140));
+		assert_eq!(Day12::part_2(&input), Answer::Number(80));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day12::parse(test_input_2()).unwrap();
+		assert_eq!(Day12::part_1(&input), Answer::Number(772));
+		assert_eq!(Day12::part_2(&input), Answer::Number(436));
+	}
+
+	#[test]
+	fn example_3() {
+		let input = Day12::parse(test_input_3()).unwrap();
+		assert_eq!(Day12::part_1(&input), Answer::Number(1930));
+		assert_eq!(Day12::part_2(&input), Answer::Number(1206));
+	}
+
+	#[test]
+	fn example_4() {
+		let input = Day12::parse(test_input_4()).unwrap();
+		assert_eq!(Day12::part_2(&input), Answer::Number(236));
+	}
+
+	#[test]
+	fn example_5() {
+		let input = Day12::parse(test_input_5()).unwrap();
+		assert_eq!(Day12::part_2(&input), Answer::Number(368));
+	}
+}
@@ -0,0 +1,338 @@
+#![allow(dead_code)]
+
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{lines, Line, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"Button A: X+94, Y+34\n",
+		"Button B: X+22, Y+67\n",
+		"Prize: X=8400, Y=5400\n",
+		"\n",
+		"Button A: X+26, Y+66\n",
+		"Button B: X+67, Y+21\n",
+		"Prize: X=12748, Y=12176\n",
+		"\n",
+		"Button A: X+17, Y+86\n",
+		"Button B: X+84, Y+37\n",
+		"Prize: X=7870, Y=6450\n",
+		"\n",
+		"Button A: X+69, Y+23\n",
+		"Button B: X+27, Y+71\n",
+		"Prize: X=18641, Y=10279\n",
+	)
+}
+
+// both buttons move in the same direction, B twice less than A
+fn test_input_2() -> &'static str {
+	concat!(
+		"Button A: X+26, Y+90\n",
+		"Button B: X+13, Y+45\n",
+		"Prize: X=1404, Y=4860\n",
+	)
+}
+
+pub type Pos = (i64, i64);
+
+// parses lines like "Button A: X+94, Y+34" or "Prize: X=8400, Y=5400"
+fn parse_machine_line(line: Line, x_prefix: &str, y_prefix: &str) -> Result<Pos, ParseError> {
+	let x_y = line.strip_prefix(line.text, x_prefix)?;
+	let (x, y) = line.split_once(x_y, y_prefix, &format!("X and Y separated by \"{}\"", y_prefix))?;
+	Ok((line.parse(x, "a number")?, line.parse(y, "a number")?))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(Pos, Pos, Pos)>, ParseError> { // (A pos delta, B pos delta, prize pos)
+	let mut machines = Vec::new();
+	let mut input_lines = lines(input).filter(|line| !line.text.is_empty());
+
+	while let Some(line) = input_lines.next() {
+		let pos_a = parse_machine_line(line, "Button A: X+", ", Y+")?;
+
+		let line = input_lines.next().ok_or_else(|| ParseError::at_end(input, "a \"Button B\" line"))?;
+		let pos_b = parse_machine_line(line, "Button B: X+", ", Y+")?;
+
+		let line = input_lines.next().ok_or_else(|| ParseError::at_end(input, "a \"Prize\" line"))?;
+		let prize = parse_machine_line(line, "Prize: X=", ", Y=")?;
+
+		machines.push((pos_a, pos_b, prize));
+	}
+
+	Ok(machines)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day13Params {
+	// added to both coordinates of the prizes in part 2
+	pub prize_offset: i64,
+}
+
+impl Default for Day13Params {
+	fn default() -> Self {
+		Day13Params { prize_offset: 10_000_000_000_000 }
+	}
+}
+
+impl Params for Day13Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("prize_offset", "added to the prize coordinates in part 2"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"prize_offset" => self.prize_offset = params::parse_value(name, value)?,
+			_ => return Err(params::unknown(name)),
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"prize_offset" => Some(self.prize_offset.to_string()),
+			_ => None,
+		}
+	}
+}
+
+// part 1 only counts the prizes that can be won with at most this many presses of each button
+pub const PART_1_MAX_PRESSES: i64 = 100;
+
+// (gcd, x, y) with a * x + b * y = gcd
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+	if b == 0 {
+		return (a, 1, 0)
+	}
+	let (gcd, x, y) = extended_gcd(b, a % b);
+	(gcd, y, x - (a / b) * y)
+}
+
+// cheapest a_presses * 3 + b_presses with a_presses * a + b_presses * b = p, for buttons that move
+// along the same line as the prize, by a non-negative amount
+fn solve_collinear(a: i128, b: i128, p: i128, max_presses: i128) -> Option<i128> {
+	if a == 0 && b == 0 {
+		return (p == 0).then_some(0)
+	}
+	if a == 0 || b == 0 {
+		let (step, cost) = if a == 0 { (b, 1) } else { (a, 3) };
+		let presses = p / step;
+		return (p % step == 0 && presses >= 0 && presses <= max_presses).then_some(presses * cost)
+	}
+
+	let (gcd, a_inverse, _) = extended_gcd(a, b);
+	if p % gcd != 0 {
+		return None
+	}
+	let (a, b, p) = (a / gcd, b / gcd, p / gcd);
+
+	// every solution is (a_presses + b * k, b_presses - a * k), starting from the fewest A presses
+	let a_presses = (p % b * a_inverse).rem_euclid(b);
+	let b_presses = (p - a_presses * a) / b;
+	if b_presses < 0 {
+		return None
+	}
+
+	// the range of k that keeps both press counts between 0 and max_presses
+	let k_min = ((b_presses - max_presses).max(0) + a - 1) / a;
+	let k_max = (b_presses / a).min((max_presses - a_presses).div_euclid(b));
+	if k_min > k_max {
+		return None
+	}
+
+	// the cost changes by 3 * b - a with each step of k, so the cheapest is at one end of the range
+	let k = if 3 * b < a { k_max } else { k_min };
+	Some((a_presses + b * k) * 3 + b_presses - a * k)
+}
+
+// inputs are just a system of 2 linear equations with 1 (or 0) solution, unless both buttons move in
+// the same direction. Computed on i128, for the products of the coordinates not to overflow
+pub fn solve_machine(machine: (Pos, Pos, Pos), max_presses: Option<i64>) -> Option<i128> {
+	let widen = |(x, y): Pos| (x as i128, y as i128);
+	let (a, b, p) = (widen(machine.0), widen(machine.1), widen(machine.2));
+	let max_presses = max_presses.unwrap_or(i64::MAX) as i128;
+
+	// using Cramer's rule
+	let a_b_det = a.0 * b.1 - b.0 * a.1;
+	if a_b_det == 0 {
+		// buttons that don't move the claw only win a prize at the start
+		if a == (0, 0) && b == (0, 0) {
+			return (p == (0, 0)).then_some(0)
+		}
+		// the buttons move along one line, which the prize has to be on
+		let direction = if a != (0, 0) { a } else { b };
+		if direction.0 * p.1 - p.0 * direction.1 != 0 {
+			return None
+		}
+		// the presses along the line are the same on both axes, as long as the axis is not perpendicular to it
+		let (a, b, p) = if direction.0 != 0 { (a.0, b.0, p.0) } else { (a.1, b.1, p.1) };
+		return solve_collinear(a, b, p, max_presses)
+	}
+
+	let p_b_det = p.0 * b.1 - b.0 * p.1;
+	let a_p_det = a.0 * p.1 - p.0 * a.1;
+
+	let a_presses = p_b_det / a_b_det;
+	let b_presses = a_p_det / a_b_det;
+
+	if a_presses < 0 || b_presses < 0 {return None}
+	if a_presses > max_presses || b_presses > max_presses {return None}
+	if a.0 * a_presses + b.0 * b_presses != p.0 {return None}
+	if a.1 * a_presses + b.1 * b_presses != p.1 {return None}
+
+	Some(a_presses * 3 + b_presses)
+}
+
+// None when a prize moved by the offset doesn't fit in an i64
+pub fn sum_machine_cost(machines: &[(Pos, Pos, Pos)], prize_offset: i64, max_presses: Option<i64>) -> Option<i128> {
+	machines.iter()
+		.map(|machine| {
+			let (a, b, prize) = *machine;
+			let prize = (prize.0.checked_add(prize_offset)?, prize.1.checked_add(prize_offset)?);
+			Some((a, b, prize))
+		})
+		.try_fold(0, |total, machine| Some(total + solve_machine(machine?, max_presses).unwrap_or(0)))
+}
+
+// the answer of a part, from sum_machine_cost
+fn cost_answer(total: Option<i128>) -> Answer {
+	total.map_or_else(|| Answer::Failed("a prize moved by the offset doesn't fit in an i64".to_string()), Answer::Number)
+}
+
+// reference for solve_machine, for part 1 only: tries every number of presses up to 100
+pub fn solve_machine_by_pressing(machine: (Pos, Pos, Pos)) -> Option<i64> {
+	let (a, b, p) = machine;
+
+	(0..=PART_1_MAX_PRESSES)
+		.flat_map(|a_presses| (0..=PART_1_MAX_PRESSES).map(move |b_presses| (a_presses, b_presses)))
+		.filter(|(a_presses, b_presses)| (a.0 * a_presses + b.0 * b_presses, a.1 * a_presses + b.1 * b_presses) == p)
+		.map(|(a_presses, b_presses)| a_presses * 3 + b_presses)
+		.min()
+}
+
+// `size` claw machines, the prizes of about half of them being reachable, and a few of them having
+// buttons that move in the same direction
+fn generate_machines(size: usize, rng: &mut Rng) -> String {
+	(0..size)
+		.map(|_| {
+			let (a, b) = if rng.chance(0.2) {
+				// both multiples of the same step
+				let step = (rng.between(2, 33), rng.between(2, 33));
+				let (a_steps, b_steps) = (rng.between(1, 3), rng.between(1, 3));
+				((step.0 * a_steps, step.1 * a_steps), (step.0 * b_steps, step.1 * b_steps))
+			} else {
+				((rng.between(10, 99), rng.between(10, 99)), (rng.between(10, 99), rng.between(10, 99)))
+			};
+			let prize = if rng.chance(0.5) {
+				let (presses_a, presses_b) = (rng.between(1, 100), rng.between(1, 100));
+				(a.0 * presses_a + b.0 * presses_b, a.1 * presses_a + b.1 * presses_b)
+			} else {
+				(rng.between(1_000, 20_000), rng.between(1_000, 20_000))
+			};
+
+			format!(
+				"Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+				a.0, a.1, b.0, b.1, prize.0, prize.1
+			)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+	const DAY: u8 = 13;
+	const REFERENCE_PARTS: &'static [u8] = &[1];
+
+	type Input = (Vec<(Pos, Pos, Pos)>, Day13Params);
+	type Params = Day13Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Day13Params::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Ok((parse_input(input)?, params.clone()))
+	}
+
+	fn part_1((machines, _params): &Self::Input) -> Answer {
+		cost_answer(sum_machine_cost(machines, 0, Some(PART_1_MAX_PRESSES)))
+	}
+
+	fn part_2((machines, params): &Self::Input) -> Answer {
+		cost_answer(sum_machine_cost(machines, params.prize_offset, None))
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_machines(size, rng))
+	}
+
+	fn reference((machines, _params): &Self::Input, _part: u8) -> Answer {
+		machines.iter().filter_map(|machine| solve_machine_by_pressing(*machine)).sum::<i64>().into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aoc_common::diff::find_divergence;
+
+	#[test]
+	fn example_1() {
+		let input = Day13::parse(test_input_1()).unwrap();
+		assert_eq!(Day13::part_1(&input), Answer::Number(480));
+		assert_eq!(Day13::part_2(&input), Answer::Number(875318608908));
+	}
+
+	#[test]
+	fn example_2() {
+		// 108 presses of B alone would be cheaper, but part 1 allows at most 100 presses of each button
+		let input = Day13::parse(test_input_2()).unwrap();
+		assert_eq!(Day13::part_1(&input), Answer::Number(112));
+		assert_eq!(Day13::reference(&input, 1), Answer::Number(112));
+	}
+
+	#[test]
+	fn collinear_buttons() {
+		// A is cheaper per step when it moves more than 3 times as far as B
+		assert_eq!(solve_machine(((8, 8), (2, 2), (20, 20)), None), Some(3 * 2 + 2));
+		assert_eq!(solve_machine(((4, 4), (2, 2), (20, 20)), None), Some(10));
+		assert_eq!(solve_machine(((4, 4), (2, 2), (20, 21)), None), None);
+		assert_eq!(solve_machine(((4, 6), (6, 9), (5, 7)), None), None);
+		assert_eq!(solve_machine(((0, 3), (0, 5), (0, 13)), None), Some(3 + 2));
+		assert_eq!(solve_machine(((26, 90), (13, 45), (1404, 4860)), None), Some(108));
+	}
+
+	#[test]
+	fn buttons_that_dont_move() {
+		assert_eq!(solve_machine(((0, 0), (0, 0), (5, 0)), None), None);
+		assert_eq!(solve_machine(((0, 0), (0, 0), (0, 5)), None), None);
+		assert_eq!(solve_machine(((0, 0), (0, 0), (0, 0)), None), Some(0));
+	}
+
+	#[test]
+	fn large_prize_offsets() {
+		let machines = parse_input(test_input_1()).unwrap();
+		assert_eq!(sum_machine_cost(&machines, i64::MAX, None), None);
+
+		// k presses of each button reach (3k, 3k), with products of the coordinates past an i64
+		let k = i64::MAX / 3;
+		assert_eq!(sum_machine_cost(&[((1, 2), (2, 1), (0, 0))], 3 * k, None), Some(4 * k as i128));
+
+		let answer = Day13::part_2(&(machines, Day13Params { prize_offset: i64::MAX }));
+		assert_eq!(answer, Answer::Failed("a prize moved by the offset doesn't fit in an i64".to_string()));
+	}
+
+	#[test]
+	fn reference_part_1() {
+		assert_eq!(find_divergence::<Day13>(1, 12, 50), Ok(None));
+	}
+}
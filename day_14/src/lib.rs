@@ -0,0 +1,387 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::Pos;
+use aoc_common::grid::Grid;
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{lines, Line, ParseError};
+use aoc_common::render::{heat, Image};
+use aoc_common::rng::Rng;
+use aoc_common::simulation::Simulation;
+use aoc_common::solution::{Answer, Solution};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"p=0,4 v=3,-3\n",
+		"p=6,3 v=-1,-3\n",
+		"p=10,3 v=-1,2\n",
+		"p=2,0 v=2,-1\n",
+		"p=0,0 v=1,3\n",
+		"p=3,0 v=-2,-2\n",
+		"p=7,6 v=-1,-3\n",
+		"p=3,0 v=-1,-2\n",
+		"p=9,3 v=2,3\n",
+		"p=7,3 v=-1,2\n",
+		"p=2,4 v=2,-3\n",
+		"p=9,5 v=-3,-3\n",
+	)
+}
+
+pub type Robot = ((i32, i32), (i32, i32));
+
+// parses "p=0,4" or "v=3,-3"
+fn parse_robot_pair(line: Line, part: &str, prefix: &str) -> Result<(i32, i32), ParseError> {
+	let pair = line.strip_prefix(part, prefix)?;
+	let (x, y) = line.split_once(pair, ",", "two numbers separated by a comma")?;
+	Ok((line.parse(x, "a number")?, line.parse(y, "a number")?))
+}
+
+// the robots have to start on the (width, height) map
+pub fn parse_input(input: &str, map_size: (i32, i32)) -> Result<Vec<Robot>, ParseError> {
+	lines(input)
+		.map(|line| {
+			let (pos_part, vel_part) = line.split_once(line.text, " ", "a position and a velocity separated by a space")?;
+			let pos = parse_robot_pair(line, pos_part, "p=")?;
+			if pos.0 < 0 || pos.0 >= map_size.0 || pos.1 < 0 || pos.1 >= map_size.1 {
+				return Err(line.error(pos_part, &format!("a position on the {}x{} map", map_size.0, map_size.1)));
+			}
+			let vel = parse_robot_pair(line, vel_part, "v=")?;
+			Ok((pos, vel))
+		})
+		.collect()
+}
+
+fn next_robot_state(robot: Robot, map_size: (i32, i32)) -> Robot {
+	let (p, v) = robot;
+	// in i64, where no velocity can overflow
+	let wrap = |coord: i32, delta: i32, size: i32| (coord as i64 + delta as i64).rem_euclid(size as i64) as i32;
+	((wrap(p.0, v.0, map_size.0), wrap(p.1, v.1, map_size.1)), v)
+}
+
+// the robots' positions wrap around the map edges
+pub fn wait_seconds(robots: Vec<Robot>, seconds: u32, map_size: (i32, i32)) -> Vec<Robot> {
+	robots.into_iter()
+		.map(|robot|
+			(0..seconds).fold(robot, |robot, _| next_robot_state(robot, map_size))
+		)
+		.collect()
+}
+
+// the product of the robot counts of the four quadrants, the middle lines excluded
+pub fn compute_safety_factor(robots: Vec<Robot>, map_size: (i32, i32)) -> i32 {
+	let (m_column, m_row) = (map_size.0 / 2, map_size.1 / 2);
+	let mut quadrant_count = [0; 4];
+
+	for (p, _) in robots {
+		if p.0 == m_column || p.1 == m_row {
+			continue;
+		}
+		match (p.0 < m_column, p.1 < m_row) {
+			(true, true) => quadrant_count[0] += 1,
+			(true, false) => quadrant_count[1] += 1,
+			(false, true) => quadrant_count[2] += 1,
+			(false, false) => quadrant_count[3] += 1,
+		}
+	}
+
+	quadrant_count.iter().filter(|&&e| e != 0).product()
+}
+
+// '#' where there are robots, one line per row
+pub fn format_map(robots: &[Robot], map_size: (i32, i32)) -> String {
+	let mut map = Grid::new(map_size.1, map_size.0, '.');
+	for (p, _) in robots {
+		map[Pos::new(p.1, p.0)] = '#';
+	}
+	map.to_string()
+}
+
+// brighter where more robots share a position
+pub fn render_robots(robots: &[Robot], map_size: (i32, i32)) -> Image {
+	let mut robot_count = vec![vec![0; map_size.0 as usize]; map_size.1 as usize];
+	for (p, _) in robots {
+		robot_count[p.1 as usize][p.0 as usize] += 1;
+	}
+
+	let mut image = Image::new((map_size.1, map_size.0), heat(0, 1));
+	for (y, row) in robot_count.into_iter().enumerate() {
+		for (x, count) in row.into_iter().enumerate() {
+			image.set(Pos::new(y as i32, x as i32), heat(count, 3));
+		}
+	}
+	image
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day14Params {
+	pub width: i32,
+	pub height: i32,
+	// waited in part 1
+	pub seconds: u32,
+	// the robots a row and a column need for the robots to look like a christmas tree
+	pub tree_row_robots: usize,
+	pub tree_column_robots: usize,
+}
+
+impl Day14Params {
+	pub fn map_size(&self) -> (i32, i32) {
+		(self.width, self.height)
+	}
+}
+
+impl Default for Day14Params {
+	fn default() -> Self {
+		Day14Params {
+			width: 101,
+			height: 103,
+			seconds: 100,
+			tree_row_robots: 30,
+			tree_column_robots: 31,
+		}
+	}
+}
+
+impl Params for Day14Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("width", "the width of the map, 11 in the example"),
+		("height", "the height of the map, 7 in the example"),
+		("seconds", "the seconds waited in part 1"),
+		("tree_row_robots", "the robots in a single row that make part 2 stop"),
+		("tree_column_robots", "the robots in a single column that make part 2 stop"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"width" => self.width = params::parse_value(name, value)?,
+			"height" => self.height = params::parse_value(name, value)?,
+			"seconds" => self.seconds = params::parse_value(name, value)?,
+			"tree_row_robots" => self.tree_row_robots = params::parse_value(name, value)?,
+			"tree_column_robots" => self.tree_column_robots = params::parse_value(name, value)?,
+			_ => return Err(params::unknown(name)),
+		}
+		if self.width <= 0 || self.height <= 0 {
+			return Err("The map width and height have to be at least 1".to_string());
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"width" => Some(self.width.to_string()),
+			"height" => Some(self.height.to_string()),
+			"seconds" => Some(self.seconds.to_string()),
+			"tree_row_robots" => Some(self.tree_row_robots.to_string()),
+			"tree_column_robots" => Some(self.tree_column_robots.to_string()),
+			_ => None,
+		}
+	}
+}
+
+// a christmas tree picture has a lot of robots lined up in the same row and column
+pub fn looks_like_christmas_tree(robots: &[Robot], params: &Day14Params) -> bool {
+	let mut column_count = vec![0; params.width as usize];
+	for (p, _) in robots {
+		column_count[p.0 as usize] += 1;
+	}
+	let mut row_count = vec![0; params.height as usize];
+	for (p, _) in robots {
+		row_count[p.1 as usize] += 1;
+	}
+
+	column_count.into_iter().max().unwrap() >= params.tree_column_robots && row_count.into_iter().max().unwrap() >= params.tree_row_robots
+}
+
+// robot positions repeat after (map width * map height) seconds, no point in looking further
+pub fn find_christmas_tree(mut robots: Vec<Robot>, params: &Day14Params) -> Option<(u32, Vec<Robot>)> {
+	for i in 1..=(params.width * params.height) as u32 {
+		robots = wait_seconds(robots, 1, params.map_size());
+		if looks_like_christmas_tree(&robots, params) {
+			return Some((i, robots))
+		}
+	}
+
+	None
+}
+
+// one second per step, until `last_second` for part 1 or until the christmas tree shows up for part 2
+struct RobotsWaiting {
+	robots: Vec<Robot>,
+	params: Day14Params,
+	seconds: u32,
+	last_second: u32,
+	until_christmas_tree: bool,
+}
+
+impl Simulation for RobotsWaiting {
+	fn step(&mut self) -> bool {
+		let found_christmas_tree = self.until_christmas_tree && self.seconds > 0 && looks_like_christmas_tree(&self.robots, &self.params);
+		if self.seconds >= self.last_second || found_christmas_tree {
+			return false;
+		}
+
+		self.robots = wait_seconds(std::mem::take(&mut self.robots), 1, self.params.map_size());
+		self.seconds += 1;
+		true
+	}
+
+	// the number of robots on each position, like in the puzzle
+	fn frame(&self) -> Grid<char> {
+		let mut frame = Grid::new(self.params.height, self.params.width, '.');
+		for (p, _) in &self.robots {
+			let cell = &mut frame[Pos::new(p.1, p.0)];
+			*cell = match *cell {
+				'.' => '1',
+				'9' => '9',
+				count => (count as u8 + 1) as char,
+			};
+		}
+		frame
+	}
+
+	fn status(&self) -> String {
+		format!("{} seconds", self.seconds)
+	}
+}
+
+// robots on the puzzle's map: the ones that draw a framed christmas tree after a random number of
+// seconds, and random ones up to `size` robots
+fn generate_robots(size: usize, rng: &mut Rng) -> String {
+	let map_size = Day14Params::default().map_size();
+	let map_size = (map_size.0 as i64, map_size.1 as i64);
+	let (width, height) = (31, 33);
+	let corner = (rng.between(0, map_size.0 - width), rng.between(0, map_size.1 - height));
+
+	let mut tree_pixels = Vec::new();
+	for x in 0..width {
+		tree_pixels.extend([(x, 0), (x, height - 1)]);
+	}
+	for y in 1..height - 1 {
+		tree_pixels.extend([(0, y), (width - 1, y)]);
+	}
+	for y in 2..height - 4 {
+		let half_width = (y / 2).min(width / 2 - 2);
+		tree_pixels.extend((-half_width..=half_width).map(|dx| (width / 2 + dx, y)));
+	}
+
+	let seconds = rng.between(1, map_size.0 * map_size.1 - 1);
+	let random_velocity = |rng: &mut Rng| loop {
+		let velocity = (rng.between(-99, 99), rng.between(-99, 99));
+		if velocity.0 != 0 && velocity.1 != 0 {
+			return velocity;
+		}
+	};
+
+	let mut robots: Vec<Robot> = tree_pixels.into_iter()
+		.map(|(x, y)| {
+			let v = random_velocity(rng);
+			let x = (corner.0 + x - v.0 * seconds).rem_euclid(map_size.0);
+			let y = (corner.1 + y - v.1 * seconds).rem_euclid(map_size.1);
+			((x as i32, y as i32), (v.0 as i32, v.1 as i32))
+		})
+		.collect();
+	while robots.len() < size {
+		let v = random_velocity(rng);
+		robots.push(((rng.between(0, map_size.0 - 1) as i32, rng.between(0, map_size.1 - 1) as i32), (v.0 as i32, v.1 as i32)));
+	}
+	rng.shuffle(&mut robots);
+
+	robots.iter()
+		.map(|(p, v)| format!("p={},{} v={},{}\n", p.0, p.1, v.0, v.1))
+		.collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+	const DAY: u8 = 14;
+
+	type Input = (Vec<Robot>, Day14Params);
+	type Params = Day14Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Day14Params::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Ok((parse_input(input, params.map_size())?, params.clone()))
+	}
+
+	fn part_1((robots, params): &Self::Input) -> Answer {
+		let map_size = params.map_size();
+		compute_safety_factor(wait_seconds(robots.clone(), params.seconds, map_size), map_size).into()
+	}
+
+	fn part_2((robots, params): &Self::Input) -> Answer {
+		match find_christmas_tree(robots.clone(), params) {
+			Some((seconds, _robots)) => seconds.into(),
+			None => Answer::Failed("no christmas tree before the robots are back where they started".to_string()),
+		}
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_robots(size, rng))
+	}
+
+	fn render((robots, params): &Self::Input) -> Vec<(&'static str, Image)> {
+		let map_size = params.map_size();
+		let mut images = vec![
+			("start", render_robots(robots, map_size)),
+			("after_part_1_seconds", render_robots(&wait_seconds(robots.clone(), params.seconds, map_size), map_size)),
+		];
+		if let Some((_seconds, robots)) = find_christmas_tree(robots.clone(), params) {
+			images.push(("christmas_tree", render_robots(&robots, map_size)));
+		}
+		images
+	}
+
+	fn simulation((robots, params): &Self::Input, part: u8) -> Option<Box<dyn Simulation>> {
+		Some(Box::new(RobotsWaiting {
+			robots: robots.clone(),
+			params: params.clone(),
+			seconds: 0,
+			last_second: if part == 1 { params.seconds } else { (params.width * params.height) as u32 },
+			until_christmas_tree: part == 2,
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		// the example robots move in a smaller space than the real ones
+		let params = Day14Params { width: 11, height: 7, ..Default::default() };
+		let input = Day14::parse_with(test_input_1(), &params).unwrap();
+		assert_eq!(Day14::part_1(&input), Answer::Number(12));
+		// the example has too few robots to draw anything
+		assert!(Day14::part_2(&input).is_failed());
+	}
+
+	#[test]
+	fn map() {
+		let robots = [((0, 0), (1, 1)), ((2, 1), (1, 1)), ((2, 1), (0, 1))];
+		assert_eq!(format_map(&robots, (3, 2)), "#..\n..#\n");
+	}
+
+	#[test]
+	fn robots_off_the_map() {
+		let params = Day14Params { width: 5, height: 5, ..Default::default() };
+		assert_eq!(Day14::parse_with(test_input_1(), &params).map(|_input| ()), Err(ParseError::new(2, 1, "p=6,3", "a position on the 5x5 map")));
+	}
+
+	#[test]
+	fn fast_robots() {
+		// velocities larger than the map wrap around it as many times as needed
+		let robots = wait_seconds(vec![((1, 1), (-23, 47)), ((0, 0), (i32::MAX, i32::MIN))], 1, (5, 5));
+		assert_eq!(robots[0].0, (3, 3));
+		assert_eq!(robots[1].0, (2, 2));
+	}
+}
@@ -0,0 +1,342 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::{Dir, Pos};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{first_problem, lines, Line, ParseError};
+use aoc_common::render::Image;
+use aoc_common::rng::Rng;
+use aoc_common::simulation::Simulation;
+use aoc_common::solution::{Answer, Solution};
+use std::fmt;
+
+pub mod part_1;
+pub mod part_2;
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"########\n",
+		"#..O.O.#\n",
+		"##@.O..#\n",
+		"#...O..#\n",
+		"#.#.O..#\n",
+		"#...O..#\n",
+		"#......#\n",
+		"########\n",
+		"\n",
+		"<^^>>>vv<v>>v<<\n",
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"##########\n",
+		"#..O..O.O#\n",
+		"#......O.#\n",
+		"#.OO..O.O#\n",
+		"#..O@..O.#\n",
+		"#O#..O...#\n",
+		"#O..O..O.#\n",
+		"#.OO.O.OO#\n",
+		"#....O...#\n",
+		"##########\n",
+		"\n",
+		"<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^\n",
+		"vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v\n",
+		"><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<\n",
+		"<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^\n",
+		"^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><\n",
+		"^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^\n",
+		">^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^\n",
+		"<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>\n",
+		"^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>\n",
+		"v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^\n",
+	)
+}
+
+fn test_input_3() -> &'static str {
+	concat!(
+		"#######\n",
+		"#...#.#\n",
+		"#.....#\n",
+		"#..OO@#\n",
+		"#..O..#\n",
+		"#.....#\n",
+		"#######\n",
+		"\n",
+		"<vv<<^^<<^^\n",
+	)
+}
+
+// The warehouse as written in the input, positions are relative to the inside of the border walls
+pub struct Layout {
+	pub size: (i32, i32),
+	pub robot: Pos,
+	pub boxes: Vec<Pos>,
+	pub walls: Vec<Pos>,
+	pub commands: Vec<Dir>,
+}
+
+pub fn parse_layout(input: &str) -> Result<Layout, ParseError> {
+	validate_layout(input).map_err(first_problem)
+}
+
+// parse_layout, going on after a problem to report all of them
+pub fn validate_layout(input: &str) -> Result<Layout, Vec<ParseError>> {
+	let mut input_lines = lines(input);
+	let mut map_lines: Vec<Line> = Vec::new();
+
+	// the map ends at the first empty line
+	loop {
+		match input_lines.next() {
+			Some(line) if line.text.is_empty() => break,
+			Some(line) => map_lines.push(line),
+			None => return Err(vec![ParseError::at_end(input, "an empty line between the map and the robot moves")]),
+		}
+	}
+
+	if map_lines.len() < 3 || map_lines[0].text.len() < 3 {
+		return Err(vec![ParseError::new(1, 1, map_lines.first().map_or("", |line| line.text), "a map with walls around it")]);
+	}
+
+	let width = map_lines[0].text.len();
+	let num_rows = map_lines.len() - 2;
+	let num_columns = width - 2;
+
+	let mut problems = Vec::new();
+	let mut robot = None;
+	let mut boxes = Vec::new();
+	let mut walls = Vec::new();
+
+	for (row, line) in map_lines.iter().enumerate() {
+		if line.text.len() != width {
+			let column = line.text.len().min(width) + 1;
+			let text = line.text.get(column - 1..).unwrap_or("");
+			problems.push(ParseError::new(line.number, column, text, &format!("a line of {} characters", width)));
+		}
+
+		let is_border_row = row == 0 || row == num_rows + 1;
+		for (column, c) in line.chars().take(width) {
+			if is_border_row || column == 1 || column == width {
+				if c != '#' {
+					problems.push(line.char_error(column, c, "a wall '#' on the border of the map"));
+				}
+				continue;
+			}
+
+			let pos = Pos::new(row as i32 - 1, (column - 2) as i32);
+			match c {
+				'.' => {},
+				'O' => boxes.push(pos),
+				'#' => walls.push(pos),
+				'@' if robot.is_none() => robot = Some(pos),
+				'@' => problems.push(line.char_error(column, c, "a single robot in the map")),
+				_ => problems.push(line.char_error(column, c, "'.', '#', 'O' or '@'")),
+			}
+		}
+	}
+
+	if robot.is_none() {
+		problems.push(ParseError::new(map_lines.len() + 1, 1, "", "a robot '@' in the map"));
+	}
+
+	let mut commands = Vec::new();
+	for line in input_lines {
+		for (column, c) in line.chars() {
+			match Dir::from_arrow(c) {
+				Some(dir) => commands.push(dir),
+				None => problems.push(line.char_error(column, c, "a robot move ('^', '>', 'v' or '<')")),
+			}
+		}
+	}
+
+	match robot {
+		Some(robot) if problems.is_empty() => Ok(Layout {
+			size: (num_rows as i32, num_columns as i32),
+			robot,
+			boxes,
+			walls,
+			commands,
+		}),
+		_ => Err(problems),
+	}
+}
+
+pub struct Warehouse {
+	pub map: part_1::Map,
+	// same warehouse, with everything except the robot twice as wide
+	pub wide_map: part_2::Map,
+	pub commands: Vec<Dir>,
+}
+
+// the robot following the commands one by one, on either map
+struct RobotMoving<M> {
+	map: M,
+	commands: Vec<Dir>,
+	num_executed: usize,
+	execute_command: fn(Dir, &mut M),
+}
+
+impl<M: fmt::Display> Simulation for RobotMoving<M> {
+	fn step(&mut self) -> bool {
+		let Some(dir) = self.commands.get(self.num_executed) else {
+			return false;
+		};
+
+		(self.execute_command)(*dir, &mut self.map);
+		self.num_executed += 1;
+		true
+	}
+
+	// the map's Display, walls included
+	fn frame(&self) -> Grid<char> {
+		Grid::parse_chars(&self.map.to_string()).expect("The map is drawn as a grid")
+	}
+
+	fn status(&self) -> String {
+		match self.num_executed {
+			0 => format!("0/{} commands", self.commands.len()),
+			n => format!("{}/{} commands, last {}", n, self.commands.len(), self.commands[n - 1].arrow()),
+		}
+	}
+}
+
+// a warehouse of `size` by `size` without its walls, with some walls and plenty of boxes inside, and
+// 8 moves per position, 80 per line
+fn generate_warehouse(size: usize, rng: &mut Rng) -> String {
+	let size = size.max(1) as i32;
+	let mut map = Grid::new(size + 2, size + 2, '#');
+	for row in 1..=size {
+		for column in 1..=size {
+			map[Pos::new(row, column)] = match rng.below(100) {
+				0..=4 => '#',
+				5..=29 => 'O',
+				_ => '.',
+			};
+		}
+	}
+	map[Pos::new(rng.between(1, size as i64) as i32, rng.between(1, size as i64) as i32)] = '@';
+
+	let moves: Vec<char> = (0..8 * size * size).map(|_| rng.choose(&Dir::ALL).arrow()).collect();
+	let moves: String = moves.chunks(80).map(|line| line.iter().collect::<String>() + "\n").collect();
+
+	format!("{}\n{}", map, moves)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+	const DAY: u8 = 15;
+
+	type Input = Warehouse;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		let layout = parse_layout(input)?;
+		Ok(Warehouse {
+			map: part_1::from_layout(&layout),
+			wide_map: part_2::from_layout(&layout),
+			commands: layout.commands,
+		})
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_layout(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		let mut map = input.map.clone();
+
+		for dir in &input.commands {
+			part_1::execute_command(*dir, &mut map);
+		}
+
+		part_1::get_gps_sum(map).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		let mut map = input.wide_map.clone();
+		let num_boxes_start = map.boxes.len();
+
+		for dir in &input.commands {
+			part_2::execute_command(*dir, &mut map);
+		}
+
+		assert_eq!(num_boxes_start, map.boxes.len(), "Number of boxes has changed");
+		part_2::get_gps_sum(map).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			3 => Some(test_input_3()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_warehouse(size, rng))
+	}
+
+	fn render(input: &Self::Input) -> Vec<(&'static str, Image)> {
+		let mut map = input.map.clone();
+		let mut wide_map = input.wide_map.clone();
+		let start_images = [("start", map.render()), ("wide_start", wide_map.render())];
+
+		for dir in &input.commands {
+			part_1::execute_command(*dir, &mut map);
+			part_2::execute_command(*dir, &mut wide_map);
+		}
+
+		start_images.into_iter()
+			.chain([("end", map.render()), ("wide_end", wide_map.render())])
+			.collect()
+	}
+
+	fn simulation(input: &Self::Input, part: u8) -> Option<Box<dyn Simulation>> {
+		let commands = input.commands.clone();
+		match part {
+			1 => Some(Box::new(RobotMoving { map: input.map.clone(), commands, num_executed: 0, execute_command: part_1::execute_command })),
+			_ => Some(Box::new(RobotMoving { map: input.wide_map.clone(), commands, num_executed: 0, execute_command: part_2::execute_command })),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day15::parse(test_input_1()).unwrap();
+		assert_eq!(Day15::part_1(&input), Answer::Number(2028));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day15::parse(test_input_2()).unwrap();
+		assert_eq!(Day15::part_1(&input), Answer::Number(10092));
+		assert_eq!(Day15::part_2(&input), Answer::Number(9021));
+	}
+
+	#[test]
+	fn example_3() {
+		let input = Day15::parse(test_input_3()).unwrap();
+		assert_eq!(Day15::part_1(&input), Answer::Number(908));
+		assert_eq!(Day15::part_2(&input), Answer::Number(618));
+	}
+
+	#[test]
+	fn check_reports_every_problem() {
+		let input = concat!(
+			"#####\n",
+			"#@.@.\n",
+			"#.x#\n",
+			"#####\n",
+			"\n",
+			"<v?\n",
+		);
+		let problems: Vec<(usize, usize)> = Day15::check(input).iter().map(|problem| (problem.line, problem.column)).collect();
+		assert_eq!(problems, [(2, 4), (2, 5), (3, 5), (3, 3), (6, 3)]);
+	}
+}
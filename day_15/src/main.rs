@@ -0,0 +1,23 @@
+use aoc_common::input::{read_day_input, MainError};
+use aoc_common::log;
+use aoc_common::solution::Solution;
+use day_15::{part_2, Day15};
+
+fn main() -> Result<(), MainError> {
+	let input = Day15::parse(&read_day_input(15)?)?;
+	println!("GPS: {}", Day15::part_1(&input));
+
+	let mut map = input.wide_map.clone();
+	log::debug!("Wide warehouse before the moves:\n{}", map);
+	let num_boxes_start = map.boxes.len();
+
+	for dir in &input.commands {
+		part_2::execute_command(*dir, &mut map);
+	}
+
+	assert_eq!(num_boxes_start, map.boxes.len(), "Number of boxes has changed");
+
+	log::debug!("Wide warehouse after the moves:\n{}", map);
+	println!("GPS (wide warehouse): {}", part_2::get_gps_sum(map));
+	Ok(())
+}
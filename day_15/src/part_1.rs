@@ -0,0 +1,119 @@
+use crate::Layout;
+use aoc_common::geometry::{is_pos_in_bounds, Dir, Pos};
+use aoc_common::render::{Image, Rgb};
+use std::collections::HashSet;
+use std::iter::repeat_n;
+
+#[derive(Debug, Clone)]
+pub struct Map {
+	size: (i32, i32),
+	robot: Pos,
+	boxes: HashSet<Pos>,
+	obstacles: HashSet<Pos>
+}
+
+impl std::fmt::Display for Map {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let border = repeat_n('#', (self.size.1 + 2) as usize).collect::<String>();
+		writeln!(f, "{}", border)?;
+
+		for row in 0..self.size.0 {
+			write!(f, "#")?;
+			for column in 0..self.size.1 {
+				let pos = Pos::new(row, column);
+				let symbol = if pos == self.robot {
+					"@"
+				} else if self.boxes.contains(&pos) {
+					"O"
+				} else if self.obstacles.contains(&pos) {
+					"#"
+				} else {
+					"."
+				};
+
+				write!(f, "{}", symbol)?;
+			}
+			writeln!(f, "#")?;
+		}
+
+		writeln!(f, "{}", border)
+	}
+}
+
+impl Map {
+	// with the walls around the warehouse, like Display
+	pub fn render(&self) -> Image {
+		let mut image = Image::new((self.size.0 + 2, self.size.1 + 2), Rgb::GREY);
+		let offset = Pos::new(1, 1);
+
+		for row in 0..self.size.0 {
+			for column in 0..self.size.1 {
+				image.set(Pos::new(row, column) + offset, Rgb::DARK_GREY);
+			}
+		}
+		image.overlay(self.obstacles.iter().map(|pos| *pos + offset), Rgb::GREY);
+		image.overlay(self.boxes.iter().map(|pos| *pos + offset), Rgb::BROWN);
+		image.set(self.robot + offset, Rgb::RED);
+		image
+	}
+}
+
+pub fn from_layout(layout: &Layout) -> Map {
+	Map {
+		size: layout.size,
+		robot: layout.robot,
+		boxes: layout.boxes.iter().copied().collect(),
+		obstacles: layout.walls.iter().copied().collect(),
+	}
+}
+
+fn pos_is_wall(pos: &Pos, map: &Map) -> bool {
+	!is_pos_in_bounds(*pos, map.size) || map.obstacles.contains(pos)
+}
+
+fn get_next_empty_pos_in_dir(dir: Dir, mut curr_pos: Pos, map: &Map) -> (Option<Pos>, Option<Pos>) { // first empty pos, first box
+	let mut first_box = None;
+
+	loop {
+		curr_pos = curr_pos.step(dir);
+		if pos_is_wall(&curr_pos, map) {
+			return (None, first_box);
+		}
+
+		if map.boxes.contains(&curr_pos) {
+			if first_box.is_none() {
+				first_box = Some(curr_pos);
+			} else {
+				continue;
+			}
+		} else {
+			// found an empty position
+			return (Some(curr_pos), first_box);
+		}
+	}
+}
+
+pub fn execute_command(dir: Dir, map: &mut Map) {
+	let (empty_pos, first_box) = get_next_empty_pos_in_dir(dir, map.robot, map);
+
+	if let Some(empty_pos) = empty_pos {
+		if let Some(first_box) = first_box {
+			// remove box
+			map.boxes.remove(&first_box);
+			// add box to new position
+			map.boxes.insert(empty_pos);
+			// move robot
+			map.robot = first_box;
+		} else {
+			// move robot
+			map.robot = empty_pos;
+		}
+	}
+}
+
+pub fn get_gps_sum(map: Map) -> i32 {
+	map.boxes.iter()
+		.map(|box_pos| *box_pos + Pos::new(1, 1))
+		.map(|box_pos| 100 * box_pos.r + box_pos.c)
+		.sum()
+}
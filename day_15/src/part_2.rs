@@ -0,0 +1,191 @@
+use crate::Layout;
+use aoc_common::geometry::{is_pos_in_bounds, Dir, Pos};
+use aoc_common::render::{Image, Rgb};
+use std::collections::HashSet;
+use std::iter::repeat_n;
+
+enum CollisionCheckType {
+	RobotToBox,
+	BoxToBox,
+	BoxToWall
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+	size: (i32, i32),
+	robot: Pos,
+	pub boxes: HashSet<Pos>,
+	obstacles: HashSet<Pos>
+}
+
+impl std::fmt::Display for Map {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let border = repeat_n('#', (self.size.1 + 4) as usize).collect::<String>();
+		writeln!(f, "{}", border)?;
+
+		for row in 0..self.size.0 {
+			write!(f, "##")?;
+			let mut last_symbol_was_box = false;
+			for column in 0..self.size.1 {
+				let pos = Pos::new(row, column);
+
+				if last_symbol_was_box {
+					last_symbol_was_box = false;
+					assert!(
+						pos != self.robot && !self.boxes.contains(&pos) && !self.obstacles.contains(&pos),
+						"Invalid map: box next to box, robot or obstacle"
+					);
+					continue;
+				}
+
+				let symbol;
+				if pos == self.robot {
+					symbol = "@";
+				} else if self.boxes.contains(&pos) {
+					symbol = "[]";
+					last_symbol_was_box = true;
+				} else if self.obstacles.contains(&pos) {
+					symbol = "#";
+				} else {
+					symbol = ".";
+				};
+
+				write!(f, "{}", symbol)?;
+			}
+			writeln!(f, "##")?;
+		}
+
+		write!(f, "{}", border)
+	}
+}
+
+// everything except the robot is twice as wide
+impl Map {
+	// with the walls around the warehouse, like Display. A box is the two cells from its position
+	pub fn render(&self) -> Image {
+		let mut image = Image::new((self.size.0 + 2, self.size.1 + 4), Rgb::GREY);
+		let offset = Pos::new(1, 2);
+
+		for row in 0..self.size.0 {
+			for column in 0..self.size.1 {
+				image.set(Pos::new(row, column) + offset, Rgb::DARK_GREY);
+			}
+		}
+		image.overlay(self.obstacles.iter().map(|pos| *pos + offset), Rgb::GREY);
+		image.overlay(self.boxes.iter().flat_map(|pos| [*pos + offset, *pos + offset + Pos::new(0, 1)]), Rgb::BROWN);
+		image.set(self.robot + offset, Rgb::RED);
+		image
+	}
+}
+
+pub fn from_layout(layout: &Layout) -> Map {
+	Map {
+		size: (layout.size.0, layout.size.1 * 2),
+		robot: Pos::new(layout.robot.r, layout.robot.c * 2),
+		boxes: layout.boxes.iter()
+			.map(|pos| Pos::new(pos.r, pos.c * 2))
+			.collect(),
+		obstacles: layout.walls.iter()
+			.flat_map(|pos| [Pos::new(pos.r, pos.c * 2), Pos::new(pos.r, pos.c * 2 + 1)])
+			.collect(),
+	}
+}
+
+fn pos_is_wall(pos: &Pos, map: &Map) -> bool {
+	!is_pos_in_bounds(*pos, map.size) || map.obstacles.contains(pos)
+}
+
+fn get_possible_blocking_pos(entity_pos: &Pos, dir: Dir, collision_type: CollisionCheckType) -> Vec<Pos> {
+	let pos_delta = dir.delta();
+
+	// position deltas to check, relative to new entity position
+	let pos_tuple_to_check = if dir == Dir::Left {
+		match collision_type {
+			CollisionCheckType::RobotToBox => vec![(0, -1)],
+			CollisionCheckType::BoxToBox => vec![(0, -1)],
+			CollisionCheckType::BoxToWall => vec![(0, 0)],
+		}
+	} else if dir == Dir::Right {
+		match collision_type {
+			CollisionCheckType::RobotToBox => vec![(0, 0)],
+			CollisionCheckType::BoxToBox => vec![(0, 1)],
+			CollisionCheckType::BoxToWall => vec![(0, 1)],
+		}
+	} else {
+		match collision_type {
+			CollisionCheckType::RobotToBox => vec![(0, -1), (0, 0)],
+			CollisionCheckType::BoxToBox => vec![(0, -1), (0, 0), (0, 1)],
+			CollisionCheckType::BoxToWall => vec![(0, 0), (0, 1)],
+		}
+	};
+
+	pos_tuple_to_check.into_iter()
+		.map(Pos::from)
+		.map(|pos| pos + *entity_pos)
+		.map(|pos| pos + pos_delta)
+		.collect()
+}
+
+pub fn execute_command(dir: Dir, map: &mut Map) {
+	let pos_delta = dir.delta();
+	let new_robot = map.robot + pos_delta;
+
+	// check if there's a wall in the way
+	if pos_is_wall(&new_robot, map) {
+		return;
+	}
+
+	// check if there are boxes in the way
+	let blocking_box_pos: Vec<Pos> = get_possible_blocking_pos(&map.robot, dir, CollisionCheckType::RobotToBox).into_iter()
+		.filter(|blocking_box| map.boxes.contains(blocking_box))
+		.collect();
+
+	if blocking_box_pos.is_empty() {
+		// there are no boxes in the way, move the robot
+		map.robot = new_robot;
+		return;
+	}
+
+	assert_eq!(blocking_box_pos.len(), 1, "Invalid map: more than one box in the robot's way");
+
+	// there's a box in the way
+	let mut boxes_to_check = Vec::new();
+	boxes_to_check.push(blocking_box_pos[0]);
+	let mut boxes_to_move = HashSet::new();
+
+	// get all boxes that we might need to move
+	while let Some(box_to_move) = boxes_to_check.pop() {
+		// check if there is a wall in the way
+		if get_possible_blocking_pos(&box_to_move, dir, CollisionCheckType::BoxToWall).into_iter()
+			.any(|pos| pos_is_wall(&pos, map)) {
+				// there's a wall in the way, can't move
+				return;
+			}
+
+		// we might be able to move this box
+		boxes_to_move.insert(box_to_move);
+
+		// check if there are other boxes in the way, and add them to the list of boxes to check
+		get_possible_blocking_pos(&box_to_move, dir, CollisionCheckType::BoxToBox).into_iter()
+			.filter(|blocking_box| map.boxes.contains(blocking_box))
+			.filter(|blocking_box| !boxes_to_move.contains(blocking_box))
+			.for_each(|blocking_box| boxes_to_check.push(blocking_box))
+	}
+
+	// move boxes and robot
+	let mut boxes_to_add = Vec::with_capacity(boxes_to_move.len());
+	for box_to_move in boxes_to_move {
+		assert!(map.boxes.remove(&box_to_move), "Box to move does not exist");
+		let box_to_add = box_to_move + pos_delta;
+		boxes_to_add.push(box_to_add);
+	}
+	boxes_to_add.iter().for_each(|box_to_add| { map.boxes.insert(*box_to_add); });
+	map.robot = new_robot;
+}
+
+pub fn get_gps_sum(map: Map) -> i32 {
+	map.boxes.iter()
+		.map(|box_pos| *box_pos + Pos::new(1, 1))
+		.map(|box_pos| (100 * box_pos.r) + box_pos.c + 1)
+		.sum()
+}
@@ -0,0 +1,712 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::{Dir, Pos};
+use aoc_common::grid::{find_in_text, Grid};
+use aoc_common::params::{self, Params};
+use aoc_common::parse::{first_problem, ParseError};
+use aoc_common::render::{char_palette, Image, Rgb};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::stats;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"#####\n",
+		"#...#\n",
+		"#S#E#\n",
+		"#####\n"
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"###############\n",
+		"#.......#....E#\n",
+		"#.#.###.#.###.#\n",
+		"#.....#.#...#.#\n",
+		"#.###.#####.#.#\n",
+		"#.#.#.......#.#\n",
+		"#.#.#####.###.#\n",
+		"#...........#.#\n",
+		"###.#.#####.#.#\n",
+		"#...#.....#.#.#\n",
+		"#.#.#.###.#.#.#\n",
+		"#.....#...#.#.#\n",
+		"#.###.#.#.#.#.#\n",
+		"#S..#.....#...#\n",
+		"###############\n"
+	)
+}
+
+fn test_input_3() -> &'static str {
+	concat!(
+		"#################\n",
+		"#...#...#...#..E#\n",
+		"#.#.#.#.#.#.#.#.#\n",
+		"#.#.#.#...#...#.#\n",
+		"#.#.#.#.###.#.#.#\n",
+		"#...#.#.#.....#.#\n",
+		"#.#.#.#.#.#####.#\n",
+		"#.#...#.#.#.....#\n",
+		"#.#.#####.#.###.#\n",
+		"#.#.#.......#...#\n",
+		"#.#.###.#####.###\n",
+		"#.#.#...#.....#.#\n",
+		"#.#.#.#####.###.#\n",
+		"#.#.#.........#.#\n",
+		"#.#.#.#########.#\n",
+		"#S#.............#\n",
+		"#################\n",
+	)
+}
+
+fn test_input_4() -> &'static str {
+	concat!(
+		"######\n",
+		"#...##\n",
+		"#S#.E#\n",
+		"#...##\n",
+		"######\n"
+	)
+}
+
+// the maze, walls are '#'
+pub struct Map {
+	pub start: Pos,
+	pub end: Pos,
+	pub positions: Grid<char>
+}
+
+// the maze reduced to its nodes (the start, the end, the dead ends and the positions where the path
+// turns or forks) and the nodes reachable from each of them in a straight line
+pub struct Graph {
+	pub start: Pos,
+	pub end: Pos,
+	pub neighbours: HashMap<Pos, HashSet<Pos>>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day16Params {
+	// the cost of a quarter turn, moving forward costs 1
+	pub turn_cost: i32,
+	pub start_facing: Dir,
+	// whether the search can start by turning around, which the puzzle's can't since it never makes U-turns
+	pub turn_around_at_start: bool,
+}
+
+impl Default for Day16Params {
+	fn default() -> Self {
+		Day16Params { turn_cost: 1000, start_facing: Dir::Right, turn_around_at_start: false }
+	}
+}
+
+impl Params for Day16Params {
+	const DESCRIPTIONS: &'static [(&'static str, &'static str)] = &[
+		("turn_cost", "the score of a quarter turn"),
+		("start_facing", "the direction the reindeer starts facing, N, E, S or W"),
+		("turn_around_at_start", "true to also start with a U-turn, at the cost of two turns"),
+	];
+
+	fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+		match name {
+			"turn_cost" => self.turn_cost = params::parse_value(name, value)?,
+			"turn_around_at_start" => self.turn_around_at_start = params::parse_value(name, value)?,
+			"start_facing" => {
+				let mut chars = value.chars();
+				self.start_facing = match (chars.next().and_then(Dir::from_compass), chars.next()) {
+					(Some(dir), None) => dir,
+					_ => return Err(format!("Invalid value for {}: {}, expected N, E, S or W", name, value)),
+				};
+			}
+			_ => return Err(params::unknown(name)),
+		}
+		Ok(())
+	}
+
+	fn get(&self, name: &str) -> Option<String> {
+		match name {
+			"turn_cost" => Some(self.turn_cost.to_string()),
+			"start_facing" => Some(self.start_facing.compass().to_string()),
+			"turn_around_at_start" => Some(self.turn_around_at_start.to_string()),
+			_ => None,
+		}
+	}
+}
+
+fn get_pos_symbol(map: &Map, pos: &Pos) -> char {
+	map.positions[*pos]
+}
+
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+// parse_input, going on after a problem to report all of them
+pub fn validate_input(input: &str) -> Result<Map, Vec<ParseError>> {
+	let grid = Grid::validate(input, "'.', '#', 'S' or 'E'", |c| matches!(c, '.' | '#' | 'S' | 'E').then_some(c));
+	let mut problems = grid.as_ref().err().cloned().unwrap_or_default();
+
+	// the walk never checks the bounds, the walls around the maze keep it in
+	if let Ok(positions) = &grid {
+		for pos in positions.border().filter(|pos| positions[*pos] != '#') {
+			problems.push(ParseError::at_cell(pos, positions[pos], "a wall '#' on the border of the maze"));
+		}
+	}
+
+	let mut find_single = |symbol: char, missing: &str, extra: &str| {
+		let found = find_in_text(input, symbol);
+		if found.is_empty() {
+			problems.push(ParseError::at_end(input, missing));
+		}
+		for pos in found.iter().skip(1) {
+			problems.push(ParseError::at_cell(*pos, symbol, extra));
+		}
+		found.first().copied()
+	};
+	let start_pos = find_single('S', "a start position 'S' in the map", "a single start position in the map");
+	let end_pos = find_single('E', "an end position 'E' in the map", "a single end position in the map");
+
+	match (grid, start_pos, end_pos) {
+		(Ok(positions), Some(start), Some(end)) if problems.is_empty() => {
+			if positions.neighbours_4(start).all(|pos| positions[pos] == '#') {
+				problems.push(ParseError::at_cell(start, 'S', "a start position next to an open position"));
+			} else if !get_reachable_positions(&positions, start).contains(&end) {
+				problems.push(ParseError::at_cell(end, 'E', "an end position reachable from the start"));
+			}
+
+			match problems.is_empty() {
+				true => Ok(Map { start, end, positions }),
+				false => Err(problems),
+			}
+		}
+		_ => Err(problems),
+	}
+}
+
+// the positions that can be walked to from `start`, whatever the turns
+fn get_reachable_positions(positions: &Grid<char>, start: Pos) -> HashSet<Pos> {
+	let mut reachable = HashSet::from([start]);
+	let mut queue = vec![start];
+
+	while let Some(pos) = queue.pop() {
+		for next_pos in positions.neighbours_4(pos) {
+			if positions[next_pos] != '#' && reachable.insert(next_pos) {
+				queue.push(next_pos);
+			}
+		}
+	}
+
+	reachable
+}
+
+fn is_pos_node(map: &Map, pos: Pos) -> bool {
+	if pos == map.start || pos == map.end {
+		return true;
+	}
+
+	let walkable_dirs = Dir::ALL.into_iter()
+		.map(|dir| (dir, pos.step(dir)))
+		.map(|(dir, new_pos)| (dir, get_pos_symbol(map, &new_pos)))
+		.filter(|(_dir, symbol)| *symbol != '#')
+		.map(|(dir, _symbol)| dir)
+		.collect::<Vec<Dir>>();
+
+	if walkable_dirs.len() != 2 {
+		return true
+	}
+
+	// if there are exactly two walkable directions, make sure they are not opposite (e.g. a straight corridor)
+	walkable_dirs[0].opposite() != walkable_dirs[1]
+}
+
+// the nodes reachable from the start, see Graph
+pub fn get_graph_from(map: &Map) -> Graph {
+	let mut neighbours: HashMap<Pos, HashSet<Pos>> = HashMap::new();
+	let mut visited: HashSet<Pos> = HashSet::new();
+	let mut queue: Vec<Pos> = vec![map.start];
+
+	while let Some(curr_pos) = queue.pop() {
+		if !visited.insert(curr_pos) {
+			continue;
+		}
+
+		for dir in Dir::ALL {
+			let facing_pos_delta = dir.delta();
+			let mut path_pos = curr_pos;
+
+			loop {
+				path_pos += facing_pos_delta;
+				if get_pos_symbol(map, &path_pos) == '#' {
+					break;
+				}
+
+				if is_pos_node(map, path_pos) {
+					neighbours.entry(curr_pos).or_default().insert(path_pos);
+					queue.push(path_pos);
+					break
+				}
+			}
+		}
+	}
+
+	stats::record("graph nodes", neighbours.len() as u64);
+	Graph {
+		start: map.start,
+		end: map.end,
+		neighbours,
+	}
+}
+
+fn get_cost_and_new_facing_to(from: &Pos, facing: &Dir, to: &Pos, turn_cost: i32) -> Option<(i32, Dir)> {
+	assert_ne!(from, to, "Cannot get cost to same position");
+
+	let pos_delta = *to - *from;
+	assert!(pos_delta.r == 0 || pos_delta.c == 0, "Can only move in straight lines");
+
+	let required_facing = if pos_delta.r > 0 {
+		Dir::Down
+	} else if pos_delta.r < 0 {
+		Dir::Up
+	} else if pos_delta.c > 0 {
+		Dir::Right
+	} else {
+		Dir::Left
+	};
+
+	if facing.opposite() == required_facing {
+		return None // don't bother with U-turns
+	}
+
+	let mut cost = from.manhattan(*to);
+	if *facing != required_facing {
+		cost += turn_cost;
+	}
+
+	Some((cost, required_facing))
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+struct State {
+	pos: Pos,
+	facing: Dir,
+	cost: i32,
+}
+
+impl PartialOrd<Self> for State {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for State {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.cost.cmp(&other.cost)
+	}
+}
+
+// the lowest score from the start to the end, and every path of nodes that reaches the end with it
+pub fn get_best_paths(graph: Graph, params: &Day16Params) -> Option<(i32, HashSet<Vec<Pos>>)> {
+	let mut heap: BinaryHeap<Reverse<State>> = BinaryHeap::new();
+	let mut cost_to: HashMap<(Pos, Dir), i32> = HashMap::new();
+
+	// U-turns are skipped below, so turning around on the start position, when it's allowed, is done
+	// here, with the quarter turns for the cost of the turns they add up to
+	let start_facings = match params.turn_around_at_start {
+		true => Dir::ALL.to_vec(),
+		false => vec![params.start_facing],
+	};
+	for facing in start_facings {
+		let num_turns = match facing {
+			facing if facing == params.start_facing => 0,
+			facing if facing == params.start_facing.opposite() => 2,
+			_ => 1,
+		};
+		let cost = num_turns * params.turn_cost;
+		heap.push(Reverse(State{
+			pos: graph.start,
+			facing,
+			cost
+		}));
+		cost_to.insert((graph.start, facing), cost);
+	}
+
+	// for each (pos, facing), store the list of pos/facings that can lead to it with the best cost
+	let mut predecessors: HashMap<(Pos, Dir), Vec<(Pos, Dir)>> = HashMap::new();
+
+	while let Some(Reverse(state)) = heap.pop() {
+		// if we found a better way to get here
+		if let Some(saved_cost_to_here) = cost_to.get(&(state.pos, state.facing)) && saved_cost_to_here < &state.cost {
+			continue;
+		}
+
+		// nodes that lead nowhere, like a start walled in on every side, have no entry
+		let Some(neighbours) = graph.neighbours.get(&state.pos) else { continue };
+		for neighbour in neighbours {
+			let Some((cost, new_facing)) = get_cost_and_new_facing_to(&state.pos, &state.facing, neighbour, params.turn_cost) else { continue };
+			let total_cost_to_neighbour = cost + state.cost;
+
+			// check if this is better than what we have saved
+			let pos_facing = (*neighbour, new_facing);
+			match cost_to.get(&pos_facing) {
+				None => {
+					// no saved cost for this pos + facing
+					cost_to.insert(pos_facing, total_cost_to_neighbour);
+					predecessors.insert(pos_facing, vec![(state.pos, state.facing)]);
+					heap.push(Reverse(State{
+						pos: *neighbour,
+						facing: new_facing,
+						cost: total_cost_to_neighbour
+					}))
+				}
+				Some(&saved_cost) if total_cost_to_neighbour < saved_cost => {
+					// we have a saved cost, but found a better way there
+					cost_to.insert(pos_facing, total_cost_to_neighbour);
+					predecessors.insert(pos_facing, vec![(state.pos, state.facing)]);
+					heap.push(Reverse(State{
+						pos: *neighbour,
+						facing: new_facing,
+						cost: total_cost_to_neighbour
+					}))
+				}
+				Some(&existing) if total_cost_to_neighbour == existing => {
+					// we found a way that has the same cost
+					predecessors.entry(pos_facing).or_default().push((state.pos, state.facing));
+				}
+				_ => {
+					// we found a way that has larger cost, ignore it
+				}
+			}
+		}
+	}
+
+	let best_cost = Dir::ALL.iter()
+		.filter_map(|dir| cost_to.get(&(graph.end, *dir)))
+		.min()
+		.copied();
+
+	if let Some(best_cost) = best_cost {
+		let mut paths = HashSet::new();
+		for dir in Dir::ALL {
+			// only compute paths with best cost
+			let end_pos_dir = (graph.end, dir);
+			if let Some(cost) = cost_to.get(&end_pos_dir) && *cost == best_cost {
+				compute_paths(&predecessors, &end_pos_dir, &graph.start, &[graph.end], &mut paths);
+			}
+		}
+		Some((best_cost, paths))
+	} else {
+		None
+	}
+}
+
+fn compute_paths(
+	predecessors: &HashMap<(Pos, Dir), Vec<(Pos, Dir)>>,
+	curr_pos_dir: &(Pos, Dir),
+	goal: &Pos,
+	curr_path: &[Pos],
+	paths: &mut HashSet<Vec<Pos>>
+) {
+	if curr_pos_dir.0 == *goal {
+		// we reached the end
+		let path = curr_path.to_vec();
+		paths.insert(path);
+		return;
+	}
+
+	let pos_predecessors = predecessors.get(curr_pos_dir).unwrap_or_else(|| panic!("Could not find a way to {:?}", curr_pos_dir));
+	assert!(!pos_predecessors.is_empty(), "Predecessors should not be empty for {:?}", curr_pos_dir);
+
+	for predecessor in pos_predecessors {
+		let mut path_fork = curr_path.to_vec();
+		path_fork.push(predecessor.0);
+		compute_paths(predecessors, predecessor, goal, &path_fork, paths);
+	}
+}
+
+fn get_pos_between(a: Pos, b: Pos) -> Vec<Pos>{
+	assert_ne!(a, b, "Positions must be different");
+	let delta = b - a;
+	assert!(delta.r == 0 || delta.c == 0, "Positions must be aligned either horizontally or vertically");
+
+	let axis_delta = Pos::new(delta.r.signum(), delta.c.signum());
+
+	let mut curr_pos = a;
+	let mut positions = vec![a];
+	while curr_pos != b {
+		curr_pos += axis_delta;
+		positions.push(curr_pos);
+	}
+
+	positions
+}
+
+fn get_all_pos_in_path(path: &[Pos]) -> HashSet<Pos> {
+	assert!(path.len() >= 2, "Path must have at least 2 positions");
+	let mut path_iter = path.iter();
+
+	let mut positions = HashSet::new();
+	let mut curr_pos = path_iter.next().unwrap();
+
+	for next_pos in path_iter {
+		get_pos_between(*curr_pos, *next_pos).iter()
+			.for_each(|pos| {
+				positions.insert(*pos);
+			});
+
+		curr_pos = next_pos;
+	}
+
+	positions
+}
+
+// every position walked along the paths, the nodes and the positions between them
+pub fn get_all_pos_in_paths(paths: &HashSet<Vec<Pos>>) -> HashSet<Pos> {
+	paths.iter()
+		.flat_map(|path| get_all_pos_in_path(path).into_iter())
+		.collect()
+}
+
+// every node with its neighbours, one map per node, for the trace log
+pub fn format_graph_node_neighbours(map: &Map, graph: &Graph) -> String {
+	let mut text = String::new();
+	for graph_entry in &graph.neighbours {
+		text += &format!("{graph_entry:?}\n");
+		let mut modified_map = map.positions.clone();
+		modified_map[*graph_entry.0] = 'N';
+
+		for neighbour in graph_entry.1 {
+			modified_map[*neighbour] = 'n';
+		}
+
+		text += &modified_map.to_string();
+	}
+	text
+}
+
+// the map with the graph nodes marked, for the debug log
+pub fn format_all_graph_nodes(map: &Map, graph: &Graph) -> String {
+	let mut modified_map = map.positions.clone();
+	for graph_entry in &graph.neighbours {
+		modified_map[*graph_entry.0] = 'N';
+	}
+
+	modified_map.to_string()
+}
+
+fn render_map(map: &Map) -> Image {
+	let palette = [('#', Rgb::GREY), ('S', Rgb::GREEN), ('E', Rgb::RED)];
+	Image::from_grid(&map.positions, char_palette(&palette, Rgb::DARK_GREY))
+}
+
+fn render_graph_nodes(map: &Map, neighbours: &HashMap<Pos, HashSet<Pos>>) -> Image {
+	let mut image = render_map(map);
+	image.tint(neighbours.keys().copied(), Rgb::BLUE);
+	image
+}
+
+fn render_best_paths(map: &Map, paths: &HashSet<Vec<Pos>>) -> Image {
+	let mut image = render_map(map);
+	image.overlay(get_all_pos_in_paths(paths), Rgb::YELLOW);
+	image.overlay([map.start], Rgb::GREEN);
+	image.overlay([map.end], Rgb::RED);
+	image
+}
+
+// a `size` by `size` maze, walls included, from the start in the bottom left corner to the end in the top
+// right one. A random depth first maze, with some walls knocked down so that there are several paths
+fn generate_maze(size: usize, rng: &mut Rng) -> String {
+	// cells are on odd positions, with walls between them
+	let size = (size.max(5) | 1) as i32;
+	let mut map = Grid::new(size, size, '#');
+	let is_cell = |pos: Pos| pos.r % 2 == 1 && pos.c % 2 == 1 && pos.r < size - 1 && pos.c < size - 1;
+
+	let start = Pos::new(size - 2, 1);
+	map[start] = '.';
+	let mut stack = vec![start];
+	while let Some(&pos) = stack.last() {
+		let unvisited: Vec<Dir> = Dir::ALL.into_iter()
+			.filter(|dir| {
+				let next = pos + dir.delta() * 2;
+				is_cell(next) && map[next] == '#'
+			})
+			.collect();
+
+		if unvisited.is_empty() {
+			stack.pop();
+			continue;
+		}
+
+		let dir = *rng.choose(&unvisited);
+		map[pos + dir.delta()] = '.';
+		map[pos + dir.delta() * 2] = '.';
+		stack.push(pos + dir.delta() * 2);
+	}
+
+	// walls between two cells, in the same row or column
+	for _ in 0..size * size / 50 {
+		let pos = Pos::new(rng.between(1, size as i64 - 2) as i32, rng.between(1, size as i64 - 2) as i32);
+		if (pos.r % 2 == 1) != (pos.c % 2 == 1) {
+			map[pos] = '.';
+		}
+	}
+
+	map[start] = 'S';
+	map[Pos::new(1, size - 2)] = 'E';
+	map.to_string()
+}
+
+// the parse only lets through mazes where the end can be reached, but not always without turning
+// around on the start position, see Day16Params::turn_around_at_start
+fn no_path() -> Answer {
+	Answer::Failed("no path from the start to the end".to_string())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+	const DAY: u8 = 16;
+
+	type Input = (Map, Day16Params);
+	type Params = Day16Params;
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Self::parse_with(input, &Day16Params::default())
+	}
+
+	fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, ParseError> {
+		Ok((parse_input(input)?, params.clone()))
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1((map, params): &Self::Input) -> Answer {
+		let Some((cost, _paths)) = get_best_paths(get_graph_from(map), params) else {
+			return no_path();
+		};
+		cost.into()
+	}
+
+	fn part_2((map, params): &Self::Input) -> Answer {
+		let Some((_cost, paths)) = get_best_paths(get_graph_from(map), params) else {
+			return no_path();
+		};
+		stats::record("best paths", paths.len() as u64);
+		get_all_pos_in_paths(&paths).len().into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			3 => Some(test_input_3()),
+			4 => Some(test_input_4()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_maze(size, rng))
+	}
+
+	fn render((map, params): &Self::Input) -> Vec<(&'static str, Image)> {
+		let graph = get_graph_from(map);
+		let mut images = vec![("graph_nodes", render_graph_nodes(map, &graph.neighbours))];
+		if let Some((_cost, paths)) = get_best_paths(graph, params) {
+			images.push(("best_paths", render_best_paths(map, &paths)));
+		}
+		images
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day16::parse(test_input_1()).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(3004));
+		assert_eq!(Day16::part_2(&input), Answer::Number(5));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day16::parse(test_input_2()).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(7036));
+		assert_eq!(Day16::part_2(&input), Answer::Number(45));
+	}
+
+	#[test]
+	fn example_3() {
+		let input = Day16::parse(test_input_3()).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(11048));
+		assert_eq!(Day16::part_2(&input), Answer::Number(64));
+	}
+
+	#[test]
+	fn example_4() {
+		// two paths of the same score, around each side of the wall next to the start
+		let input = Day16::parse(test_input_4()).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(4005));
+		assert_eq!(Day16::part_2(&input), Answer::Number(9));
+	}
+
+	#[test]
+	fn cheaper_turns() {
+		let input = Day16::parse_with(test_input_2(), &Day16Params { turn_cost: 1, ..Default::default() }).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(38));
+	}
+
+	#[test]
+	fn check_reports_every_problem() {
+		let input = concat!(
+			"#####\n",
+			"#S.S#\n",
+			"#..\n",
+			"#..E.\n",
+		);
+		let problems: Vec<(usize, usize)> = Day16::check(input).iter().map(|problem| (problem.line, problem.column)).collect();
+		assert_eq!(problems, [(3, 4), (2, 4)]);
+
+		let input = "#####\n#S.S.\n#..E#\n#####\n";
+		let problems: Vec<(usize, usize)> = Day16::check(input).iter().map(|problem| (problem.line, problem.column)).collect();
+		assert_eq!(problems, [(2, 5), (2, 4)]);
+	}
+
+	#[test]
+	fn check_rejects_mazes_without_a_path() {
+		// walled in start
+		assert_eq!(Day16::check("#####\n#S#E#\n#####\n"), [ParseError::new(2, 2, "S", "a start position next to an open position")]);
+		assert_eq!(Day16::check("######\n#S.#E#\n######\n"), [ParseError::new(2, 5, "E", "an end position reachable from the start")]);
+	}
+
+	#[test]
+	fn no_path_from_a_walled_in_start() {
+		let map = Map {
+			start: Pos::new(1, 1),
+			end: Pos::new(1, 3),
+			positions: Grid::parse_chars("#####\n#S#E#\n#####\n").unwrap(),
+		};
+		assert!(get_best_paths(get_graph_from(&map), &Day16Params::default()).is_none());
+		assert!(Day16::part_1(&(map, Day16Params::default())).is_failed());
+	}
+
+	#[test]
+	fn no_path_without_turning_around() {
+		// the only way out of the start is behind the reindeer
+		let map = parse_input("#####\n#E.S#\n#####\n").unwrap();
+		assert!(get_best_paths(get_graph_from(&map), &Day16Params::default()).is_none());
+		let input = (map, Day16Params::default());
+		assert!(Day16::part_1(&input).is_failed());
+		assert!(Day16::part_2(&input).is_failed());
+
+		let params = Day16Params { turn_around_at_start: true, ..Default::default() };
+		let input = Day16::parse_with("#####\n#E.S#\n#####\n", &params).unwrap();
+		assert_eq!(Day16::part_1(&input), Answer::Number(2002));
+		assert_eq!(Day16::part_2(&input), Answer::Number(3));
+	}
+}
@@ -0,0 +1,343 @@
+use aoc_common::parse::{first_problem, lines, Line, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"Register A: 729\n",
+		"Register B: 0\n",
+		"Register C: 0\n",
+		"\n",
+		"Program: 0,1,5,4,3,0\n"
+	)
+}
+
+// run_program gives up on programs that run longer than this, as they likely never halt
+pub const MAX_STEPS: u64 = 1_000_000;
+
+// the 3-bit computer of the puzzle, run with run_program after setting its registers
+#[derive(Debug, Clone)]
+pub struct Computer {
+	pub reg_a: u128,
+	pub reg_b: u128,
+	pub reg_c: u128,
+	// opcodes and operands, all from 0 to 7
+	pub instructions: Vec<u8>,
+	inst_pointer: u32,
+	output: Vec<u8>,
+}
+
+impl Computer {
+	pub fn new(reg_a: u128, reg_b: u128, reg_c: u128, instructions: Vec<u8>) -> Self {
+		Computer {
+			reg_a,
+			reg_b,
+			reg_c,
+			instructions,
+			inst_pointer: 0,
+			output: Vec::new(),
+		}
+	}
+
+	fn get_instruction(&self, offset: u32) -> Result<u8, &'static str> {
+		self.instructions
+			.get((self.inst_pointer + offset) as usize)
+			.ok_or("Instruction pointer out of bounds")
+			.copied()
+	}
+
+	fn get_combo_operand_value(&self, operand: u8) -> u128 {
+		if operand <= 3 {
+			operand as u128
+		} else if operand == 4 {
+			self.reg_a
+		} else if operand == 5 {
+			self.reg_b
+		} else if operand == 6 {
+			self.reg_c
+		} else {
+			panic!("Invalid operand value: {}", operand);
+		}
+	}
+
+	// A divided by 2 to the power of the combo operand, which is 0 once the operand is 128 or more
+	fn divide_reg_a(&self, combo_operand: u128) -> u128 {
+		u32::try_from(combo_operand).ok()
+			.and_then(|shift| self.reg_a.checked_shr(shift))
+			.unwrap_or(0)
+	}
+
+	fn run_adv_instruction(&mut self) -> Result<(), &'static str> {
+		let combo_operand = self.get_combo_operand_value(
+			self.get_instruction(1)?
+		);
+
+		self.reg_a = self.divide_reg_a(combo_operand);
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_bxl_instruction(&mut self) -> Result<(), &'static str> {
+		self.reg_b ^= self.get_instruction(1)? as u128;
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_bst_instruction(&mut self) -> Result<(), &'static str> {
+		let combo_operand = self.get_combo_operand_value(
+			self.get_instruction(1)?
+		);
+
+		self.reg_b = combo_operand % 8;
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_jnz_instruction(&mut self) -> Result<(), &'static str> {
+		if self.reg_a != 0 {
+			self.inst_pointer = self.get_instruction(1)? as u32;
+		} else {
+			self.inst_pointer += 2;
+		}
+
+		Ok(())
+	}
+
+	fn run_bxc_instruction(&mut self) -> Result<(), &'static str> {
+		// The operand for BXC is intentionally ignored as per the instruction specification.
+		self.get_instruction(1)?;
+
+		self.reg_b ^= self.reg_c;
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_out_instruction(&mut self) -> Result<(), &'static str> {
+		let combo_operand = self.get_combo_operand_value(
+			self.get_instruction(1)?
+		);
+
+		self.output.push((combo_operand % 8) as u8);
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_bdv_instruction(&mut self) -> Result<(), &'static str> {
+		let combo_operand = self.get_combo_operand_value(
+			self.get_instruction(1)?
+		);
+
+		self.reg_b = self.divide_reg_a(combo_operand);
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	fn run_cdv_instruction(&mut self) -> Result<(), &'static str> {
+		let combo_operand = self.get_combo_operand_value(
+			self.get_instruction(1)?
+		);
+
+		self.reg_c = self.divide_reg_a(combo_operand);
+
+		self.inst_pointer += 2;
+		Ok(())
+	}
+
+	// runs the instruction at the instruction pointer, an error once the program halted
+	pub fn run_instruction(&mut self) -> Result<(), &'static str> {
+		let opcode = self.get_instruction(0)?;
+		match opcode {
+		    0 => self.run_adv_instruction()?,
+			1 => self.run_bxl_instruction()?,
+			2 => self.run_bst_instruction()?,
+			3 => self.run_jnz_instruction()?,
+			4 => self.run_bxc_instruction()?,
+			5 => self.run_out_instruction()?,
+			6 => self.run_bdv_instruction()?,
+			7 => self.run_cdv_instruction()?,
+			_ => panic!("Unknown opcode: {}", opcode),
+		};
+
+		Ok(())
+	}
+
+	// false when the program is still running after MAX_STEPS instructions
+	pub fn run_program(&mut self) -> bool {
+		for _ in 0..MAX_STEPS {
+			if self.run_instruction().is_err() {
+				return true;
+			}
+		}
+		false
+	}
+
+	// the values output so far
+	pub fn output(&self) -> &[u8] {
+		&self.output
+	}
+
+	// the output as the puzzle's answer, the values separated by commas
+	pub fn get_output_string(&self) -> String {
+		self.output.iter()
+			.map(|n| n.to_string())
+			.collect::<Vec<_>>()
+			.join(",")
+	}
+}
+
+fn parse_register(line: Line, name: char) -> Result<u128, ParseError> {
+	let value = line.strip_prefix(line.text, &format!("Register {}: ", name))?;
+	line.parse(value, &format!("a number for register {}", name))
+}
+
+fn parse_instruction(line: Line, instruction: &str) -> Result<u8, ParseError> {
+	match line.parse::<u8>(instruction, "a 3-bit number") {
+		Ok(value) if value <= 7 => Ok(value),
+		_ => Err(line.error(instruction, "a 3-bit number")),
+	}
+}
+
+// the opcodes that take a combo operand, for which 7 is not valid
+const COMBO_OPCODES: [u8; 5] = [0, 2, 5, 6, 7];
+
+pub fn parse_input(input: &str) -> Result<Computer, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+// parse_input, going on after a problem to report all of them
+pub fn validate_input(input: &str) -> Result<Computer, Vec<ParseError>> {
+	let mut input_lines = lines(input);
+	let mut problems = Vec::new();
+	// a missing line ends the validation, along with the problems found before it
+	let missing = |mut problems: Vec<ParseError>, expected: &str| {
+		problems.push(ParseError::at_end(input, expected));
+		problems
+	};
+
+	let mut registers = [0; 3];
+	for (register, name) in registers.iter_mut().zip(['A', 'B', 'C']) {
+		let Some(line) = input_lines.next() else {
+			return Err(missing(problems, &format!("a \"Register {}\" line", name)));
+		};
+		parse_register(line, name).map_or_else(|problem| problems.push(problem), |value| *register = value);
+	}
+
+	let Some(empty_line) = input_lines.next() else {
+		return Err(missing(problems, "an empty line"));
+	};
+	if !empty_line.text.is_empty() {
+		problems.push(empty_line.error(empty_line.text, "an empty line"));
+	}
+
+	let Some(line) = input_lines.next() else {
+		return Err(missing(problems, "a \"Program\" line"));
+	};
+	let program = match line.strip_prefix(line.text, "Program: ") {
+		Ok(program) => program,
+		Err(problem) => {
+			problems.push(problem);
+			return Err(problems);
+		}
+	};
+
+	let mut instructions = Vec::new();
+	for instruction in program.split(",") {
+		parse_instruction(line, instruction).map_or_else(|problem| problems.push(problem), |value| instructions.push(value));
+	}
+
+	// only checked on a program that parsed, so that the opcodes line up with their operands
+	if problems.is_empty() {
+		let operands: Vec<&str> = program.split(",").collect();
+		for (i, pair) in instructions.chunks_exact(2).enumerate() {
+			if let [opcode, 7] = pair && COMBO_OPCODES.contains(opcode) {
+				problems.push(line.error(operands[2 * i + 1], "a combo operand from 0 to 6"));
+			}
+		}
+	}
+
+	let [reg_a, reg_b, reg_c] = registers;
+	match problems.is_empty() {
+		true => Ok(Computer::new(reg_a, reg_b, reg_c, instructions)),
+		false => Err(problems),
+	}
+}
+
+// a program shaped like the puzzle's: each loop outputs a value computed from the low bits of A and
+// shifts A by 3 bits, so it outputs `size` values, at most 42 for A to fit in a u128
+fn generate_program(size: usize, rng: &mut Rng) -> String {
+	let size = size.clamp(1, 42) as u32;
+	let low_bits = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+	let reg_a = 8_u128.pow(size - 1) + low_bits % (7 * 8_u128.pow(size - 1));
+
+	let program = [2, 4, 1, rng.below(8), 7, 5, 1, rng.below(8), 4, rng.below(8), 0, 3, 5, 5, 3, 0];
+	let program = program.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+
+	format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", reg_a, program)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+	const DAY: u8 = 17;
+	const PARTS: u8 = 1;
+
+	type Input = Computer;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		let mut computer = input.clone();
+		match computer.run_program() {
+			true => computer.get_output_string().into(),
+			false => Answer::Failed(format!("the program is still running after {} instructions", MAX_STEPS)),
+		}
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_program(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day17::parse(test_input_1()).unwrap();
+		assert_eq!(Day17::part_1(&input), Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string()));
+	}
+
+	#[test]
+	fn large_shifts() {
+		// A is divided by 2 to the power of B = 200, then output
+		let input = Day17::parse("Register A: 1\nRegister B: 200\nRegister C: 0\n\nProgram: 0,5,5,4\n").unwrap();
+		assert_eq!(Day17::part_1(&input), Answer::Text("0".to_string()));
+	}
+
+	#[test]
+	fn programs_that_never_halt() {
+		let input = Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n").unwrap();
+		assert_eq!(Day17::part_1(&input), Answer::Failed("the program is still running after 1000000 instructions".to_string()));
+	}
+}
@@ -0,0 +1,156 @@
+use aoc_common::parse::{lines, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"7 6 4 2 1\n",
+		"1 2 7 8 9\n",
+		"9 7 6 2 1\n",
+		"1 3 2 4 5\n",
+		"8 6 4 4 1\n",
+		"1 3 6 7 9\n",
+	)
+}
+
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+	lines(input)
+		.map(|line| {
+			let report = line.text.split(" ")
+				.map(|value| line.parse::<i32>(value, "a level"))
+				.collect::<Result<Vec<i32>, _>>()?;
+
+			if report.len() < 2 {
+				return Err(line.error(line.text, "at least two levels"));
+			}
+
+			Ok(report)
+		})
+		.collect()
+}
+
+// safe when the levels all increase or all decrease, by 1 to 3 at each step
+pub fn check_report(report: &[i32]) -> bool {
+	// what's left of a two level report once one is removed
+	if report.len() < 2 {return true}
+
+	let first_diff = report[0] - report[1];
+	if first_diff == 0 || first_diff.abs() > 3 {return false}
+
+	let decreasing = first_diff < 0;
+
+	for i in 1..(report.len() - 1) {
+		let diff = report[i] - report[i + 1];
+		if diff == 0 || diff.abs() > 3 {return false}
+		if decreasing {
+			if diff > 0 {return false}
+		} else if diff < 0 {return false}
+	}
+
+	true
+}
+
+pub fn count_safe_reports(reports: Vec<Vec<i32>>) -> usize {
+	reports.iter().filter(|report| check_report(report)).count()
+}
+
+pub fn count_safe_reports_tolerant(reports: Vec<Vec<i32>>) -> usize {
+
+	let mut safe_reports = 0;
+
+	for report in reports {
+		if check_report(&report) {
+			safe_reports += 1
+		} else {
+			let found_solution = (0..report.len())
+				.any(|ignore|
+					// This is kinda terrible
+					check_report(&report.iter()
+						.enumerate()
+						.filter(|&(i, _)| i != ignore)
+						.map(|(_, &v)| v)
+						.collect::<Vec<i32>>()
+					)
+				);
+			safe_reports += if found_solution {1} else {0};
+		}
+	}
+
+	safe_reports
+}
+
+// `size` reports of 5 to 8 levels, starting safe and then sometimes broken in one or more places
+fn generate_reports(size: usize, rng: &mut Rng) -> String {
+	(0..size)
+		.map(|_| {
+			let increasing = rng.chance(0.5);
+			let mut level = if increasing { rng.between(10, 70) } else { rng.between(30, 90) };
+			let mut report: Vec<i64> = (0..rng.between(5, 8))
+				.map(|_| {
+					let current = level;
+					level += if increasing { rng.between(1, 3) } else { -rng.between(1, 3) };
+					current
+				})
+				.collect();
+
+			while rng.chance(0.4) {
+				let i = rng.below(report.len());
+				report[i] = rng.between(1, 99);
+			}
+
+			report.iter().map(|level| level.to_string()).collect::<Vec<_>>().join(" ") + "\n"
+		})
+		.collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+	const DAY: u8 = 2;
+
+	type Input = Vec<Vec<i32>>;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_reports(input)
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		count_safe_reports(input.clone()).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		count_safe_reports_tolerant(input.clone()).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_reports(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day2::parse(test_input_1()).unwrap();
+		assert_eq!(Day2::part_1(&input), Answer::Number(2));
+		assert_eq!(Day2::part_2(&input), Answer::Number(4));
+	}
+
+	#[test]
+	fn two_levels() {
+		// removing either level of a two level report leaves a single level, which is safe
+		let input = Day2::parse("1 1\n5 9\n").unwrap();
+		assert_eq!(Day2::part_1(&input), Answer::Number(0));
+		assert_eq!(Day2::part_2(&input), Answer::Number(2));
+	}
+}
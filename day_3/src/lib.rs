@@ -0,0 +1,129 @@
+use aoc_common::parse::ParseError;
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+#[allow(dead_code)]
+fn test_input_1() -> &'static str {
+	"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+}
+
+#[allow(dead_code)]
+fn test_input_2() -> &'static str {
+	"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+}
+
+#[allow(dead_code)]
+fn test_input_3() -> &'static str {
+	"xmul(2,4)&mul[3,7]mul(20,40)!^don't()_mul(5,5)do()mul(69,69)don't()+mul(32,64]do()mul(5,5)mul(5,1)don't()(mul(11,8)undo()?mul(8,5))"
+}
+
+// the sum of the products of every valid mul(X,Y) instruction
+pub fn sum_of_multiplications(input: &str) -> u32 {
+	let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+	re.captures_iter(input)
+		.map(|capture| capture.extract::<2>())
+		.map(|(_, [a, b])| (a, b))
+		.map(|(a, b)| (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap()))
+		.map(|(a, b)| a * b)
+		.sum()
+}
+
+// same, ignoring the instructions between a don't() and the next do()
+pub fn sum_of_multiplications_only_allowed_regions(input: &str) -> u32 {
+	// regex to get allowed memory regions, from the start or a do() up to the next don't() or the end of the input
+	let re_allowed_regions = Regex::new(r"^(?:.|\n)*?(?:don't\(\)|$)|do\(\)(?:.|\n)*?(?:don't\(\)|$)").unwrap();
+	let re_mul = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+	re_allowed_regions.captures_iter(input)
+		.map(|allowed_memory_capture| allowed_memory_capture.get(0).unwrap().as_str())
+		.map(|allowed_memory| re_mul.captures_iter(allowed_memory))
+		.flat_map(|capture_match| capture_match.into_iter())
+		.map(|capture| capture.extract::<2>())
+		.map(|(_, [a, b])| (a, b))
+		.map(|(a, b)| (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap()))
+		.map(|(a, b)| a * b)
+		.sum()
+}
+
+// `size` pieces of memory: valid multiplications, do() and don't(), almost valid instructions and noise
+fn generate_memory(size: usize, rng: &mut Rng) -> String {
+	let noise = ['!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '[', ']', '{', '}', '<', '>', ',', ';', ':', '\'', '?', ' ', '+', '-', '_', '/', '~', 'x', 'm', 'u', 'l'];
+	let mut memory = String::new();
+
+	for _ in 0..size {
+		match rng.below(10) {
+			0..=3 => memory += &format!("mul({},{})", rng.between(1, 999), rng.between(1, 999)),
+			4 => memory += "do()",
+			5 => memory += "don't()",
+			6 => memory += &format!("mul[{},{}]", rng.between(1, 999), rng.between(1, 999)),
+			7 => memory += &format!("mul({}, {})", rng.between(1, 999), rng.between(1, 999)),
+			8 if rng.chance(0.05) => memory.push('\n'),
+			_ => memory.push(*rng.choose(&noise)),
+		}
+	}
+
+	memory + "\n"
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+	const DAY: u8 = 3;
+
+	type Input = String;
+	type Params = ();
+
+	// any text is valid corrupted memory
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		Ok(input.to_string())
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		sum_of_multiplications(input).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		sum_of_multiplications_only_allowed_regions(input).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			3 => Some(test_input_3()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_memory(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day3::parse(test_input_1()).unwrap();
+		assert_eq!(Day3::part_1(&input), Answer::Number(161));
+		// there are no don't() instructions, so every multiplication is enabled
+		assert_eq!(Day3::part_2(&input), Answer::Number(161));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day3::parse(test_input_2()).unwrap();
+		assert_eq!(Day3::part_2(&input), Answer::Number(48));
+	}
+
+	#[test]
+	fn example_3() {
+		let input = Day3::parse(test_input_3()).unwrap();
+		assert_eq!(Day3::part_1(&input), Answer::Number(5752));
+		assert_eq!(Day3::part_2(&input), Answer::Number(5639));
+	}
+}
@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+use aoc_common::grid::Grid;
+use aoc_common::parse::{first_problem, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"XMAS--\n",
+		"M---X-\n",
+		"A---M-\n",
+		"S---A-\n",
+		"----S-\n"
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"MMMSXXMASM\n",
+		"MSAMXMSMSA\n",
+		"AMXSXMAAMM\n",
+		"MSAMASMSMX\n",
+		"XMASAMXAMM\n",
+		"XXAMMXXAMA\n",
+		"SMSMSASXSS\n",
+		"SAXAMASAAA\n",
+		"MAMMMXMMMM\n",
+		"MXMXAXMASX\n"
+	)
+}
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+// the regexes are built from the line length, so we need at least one complete, non empty line, and
+// the others as long as it
+pub fn validate_input(input: &str) -> Result<String, Vec<ParseError>> {
+	match input.find('\n') {
+		None => Err(vec![ParseError::at_end(input, "a line break after the first row")]),
+		Some(0) => Err(vec![ParseError::new(1, 1, "", "a row of letters")]),
+		Some(_) => Grid::validate(input, "any character", Some).map(|_grid| input.to_string()),
+	}
+}
+
+// XMAS in every direction, including backwards and diagonally
+pub fn count_xmas(input: &str) -> usize {
+	let line_len = input.chars().position(|c| c == '\n').unwrap();
+
+	// Since this regex engine does not support lookahead to find overlapping matches, I am forced to do this fuckery
+	let regexes: Vec<Regex> = [
+		r"^XMAS".to_string(), // right
+		format!(r"^X(?:.|\n){{{}}}M(?:.|\n){{{}}}A(?:.|\n){{{}}}S", line_len + 1, line_len + 1, line_len + 1), // down right
+		format!(r"^X(?:.|\n){{{}}}M(?:.|\n){{{}}}A(?:.|\n){{{}}}S", line_len, line_len, line_len), // down
+		format!(r"^X(?:.|\n){{{}}}M(?:.|\n){{{}}}A(?:.|\n){{{}}}S", line_len - 1, line_len - 1, line_len - 1), // down left
+		r"^SAMX".to_string(), // left
+		format!(r"^S(?:.|\n){{{}}}A(?:.|\n){{{}}}M(?:.|\n){{{}}}X", line_len + 1, line_len + 1, line_len + 1), // up left
+		format!(r"^S(?:.|\n){{{}}}A(?:.|\n){{{}}}M(?:.|\n){{{}}}X", line_len, line_len, line_len), // up
+		format!(r"^S(?:.|\n){{{}}}A(?:.|\n){{{}}}M(?:.|\n){{{}}}X", line_len - 1, line_len - 1, line_len - 1) // up right
+	].iter()
+		.map(|re_pattern| Regex::new(re_pattern).unwrap())
+		.collect();
+
+	count_regex_matches_in_input(&input, regexes)
+}
+
+// two MAS crossing on their A, each of them forwards or backwards
+pub fn count_x_mas(input: &str) -> usize {
+	let line_len = input.chars().position(|c| c == '\n').unwrap();
+
+	let regexes: Vec<Regex> = [
+		format!(r"^M.S(?:.|\n){{{}}}A(?:.|\n){{{}}}M.S", line_len - 1, line_len - 1), // Ms on the left
+		format!(r"^M.M(?:.|\n){{{}}}A(?:.|\n){{{}}}S.S", line_len - 1, line_len - 1), // Ms on the top
+		format!(r"^S.M(?:.|\n){{{}}}A(?:.|\n){{{}}}S.M", line_len - 1, line_len - 1), // Ms on the right
+		format!(r"^S.S(?:.|\n){{{}}}A(?:.|\n){{{}}}M.M", line_len - 1, line_len - 1), // Ms on the bottom
+	].iter()
+		.map(|re_pattern| Regex::new(re_pattern).unwrap())
+		.collect();
+
+	count_regex_matches_in_input(&input, regexes)
+}
+
+fn count_regex_matches_in_input(input: &&str, regexes: Vec<Regex>) -> usize {
+	input.char_indices()
+		.map(|(i, _)| i)
+		.flat_map(|i| regexes.iter()
+			.filter_map(move |re| re.captures(&input[i..]))
+		)
+		.count()
+}
+
+// a `size` by `size` square of X, M, A and S
+fn generate_letters(size: usize, rng: &mut Rng) -> String {
+	(0..size)
+		.map(|_| (0..size).map(|_| *rng.choose(&['X', 'M', 'A', 'S'])).collect::<String>() + "\n")
+		.collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+	const DAY: u8 = 4;
+
+	type Input = String;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		count_xmas(input).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		count_x_mas(input).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_letters(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day4::parse(test_input_1()).unwrap();
+		assert_eq!(Day4::part_1(&input), Answer::Number(3));
+		// no A is the middle of two crossed MAS
+		assert_eq!(Day4::part_2(&input), Answer::Number(0));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day4::parse(test_input_2()).unwrap();
+		assert_eq!(Day4::part_1(&input), Answer::Number(18));
+		assert_eq!(Day4::part_2(&input), Answer::Number(9));
+	}
+}
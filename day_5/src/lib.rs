@@ -0,0 +1,240 @@
+#![allow(dead_code)]
+
+use aoc_common::parse::{first_problem, lines, Line, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+// the (before, after) page ordering rules, and the pages of each update
+pub type RulesAndUpdates = (Vec<(u32, u32)>, Vec<Vec<u32>>);
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"47|53\n",
+		"97|13\n",
+		"97|61\n",
+		"97|47\n",
+		"75|29\n",
+		"61|13\n",
+		"75|53\n",
+		"29|13\n",
+		"97|29\n",
+		"53|29\n",
+		"61|53\n",
+		"97|53\n",
+		"61|29\n",
+		"47|13\n",
+		"75|47\n",
+		"97|75\n",
+		"47|61\n",
+		"75|61\n",
+		"47|29\n",
+		"75|13\n",
+		"53|13\n",
+		"\n",
+		"75,47,61,53,29\n",
+		"97,61,53,29,13\n",
+		"75,29,13\n",
+		"75,97,47,61,53\n",
+		"61,13,29\n",
+		"97,13,75,29,47\n",
+	)
+}
+
+fn parse_rule(line: Line) -> Result<(u32, u32), ParseError> {
+	let (before, after) = line.split_once(line.text, "|", "a rule like \"47|53\"")?;
+	Ok((line.parse::<u32>(before, "a page number")?, line.parse::<u32>(after, "a page number")?))
+}
+
+// rules and manuals are separated by an empty line
+pub fn parse_input(input: &str) -> Result<RulesAndUpdates, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+// parse_input, going on after a problem to report all of them
+pub fn validate_input(input: &str) -> Result<RulesAndUpdates, Vec<ParseError>> {
+	let mut problems = Vec::new();
+	let mut rules = Vec::new();
+	let mut manual_lines = Vec::new();
+	let mut in_rules = true;
+
+	for line in lines(input) {
+		if in_rules && line.text.is_empty() {
+			in_rules = false;
+		} else if in_rules && !line.text.contains('|') && line.text.contains(',') {
+			// an update right after the rules, the separator is missing rather than every update being a bad rule
+			problems.push(ParseError::new(line.number, 1, line.text, "an empty line between the rules and the updates"));
+			in_rules = false;
+			manual_lines.push(line);
+		} else if in_rules {
+			parse_rule(line).map_or_else(|problem| problems.push(problem), |rule| rules.push(rule));
+		} else {
+			manual_lines.push(line);
+		}
+	}
+	if in_rules {
+		problems.push(ParseError::at_end(input, "an empty line between the rules and the updates"));
+	}
+
+	let mut manuals = Vec::new();
+	for line in manual_lines {
+		let pages: Vec<&str> = line.text.split(",").collect();
+		let mut manual = Vec::new();
+		for page in &pages {
+			line.parse::<u32>(page, "a page number").map_or_else(|problem| problems.push(problem), |page| manual.push(page));
+		}
+		// there would be no middle page
+		if manual.len() == pages.len() && manual.len() % 2 == 0 {
+			problems.push(line.error(line.text, "an odd number of pages"));
+		}
+		manuals.push(manual);
+	}
+
+	match problems.is_empty() {
+		true => Ok((rules, manuals)),
+		false => Err(problems),
+	}
+}
+
+pub fn manual_is_invalid(manual: &[u32], page_to_pages_after: &HashMap<u32, HashSet<u32>>) -> bool {
+	manual.iter().enumerate().rev()
+		.any(|(i, page)| {
+			if let Some(pages_after) = page_to_pages_after.get(page) {
+				manual[..i].iter().any(|page| pages_after.contains(page))
+			} else {
+				false
+			}
+		})
+}
+
+// the sum of the middle pages of the updates that follow every rule
+pub fn valid_manuals_middle_page_sum(rules: &[(u32, u32)], manuals: &[Vec<u32>]) -> u32 {
+	let mut page_to_pages_after: HashMap<u32, HashSet<u32>> = HashMap::new();
+
+	for (bef, aft) in rules {
+		page_to_pages_after.entry(*bef).or_default().insert(*aft);
+	}
+
+	manuals.iter()
+		.map(|manual| {
+			(manual, manual_is_invalid(manual, &page_to_pages_after))
+		})
+		.filter(|(_manual, invalid)| !*invalid)
+		.map(|(manual, _valid)| manual.get(manual.len() / 2).unwrap())
+		.sum()
+}
+
+fn cmp_pages_rule_ordering(page_to_pages_after: &HashMap<u32, HashSet<u32>>, a: &u32, b: &u32) -> Ordering {
+	if page_to_pages_after.get(a).is_some_and(|pages_after| pages_after.contains(b)) {
+		Ordering::Less
+	} else if page_to_pages_after.get(b).is_some_and(|pages_after| pages_after.contains(a)) {
+		Ordering::Greater
+	} else {
+		Ordering::Equal
+	}
+}
+
+// the sum of the middle pages of the other updates, once sorted to follow the rules
+pub fn corrected_manuals_middle_page_sum(rules: &[(u32, u32)], manuals: &[Vec<u32>]) -> u32 {
+	let mut page_to_pages_after: HashMap<u32, HashSet<u32>> = HashMap::new();
+
+	for (bef, aft) in rules {
+		page_to_pages_after.entry(*bef).or_default().insert(*aft);
+	}
+
+	manuals.iter()
+		.map(|manual| {
+			(manual, manual_is_invalid(manual, &page_to_pages_after))
+		})
+		.filter(|(_manual, not_valid)| *not_valid)
+		.map(|(manual, _not_valid)| manual)
+		.map(|manual| {
+			let mut manual_clone = manual.clone();
+			manual_clone.sort_by(|a, b|
+				cmp_pages_rule_ordering(&page_to_pages_after, a, b)
+			);
+			manual_clone
+		})
+		.map(|manual| *manual.get(manual.len() / 2).unwrap())
+		.sum()
+}
+
+// `size` updates of an odd number of pages, about half of them in the right order. There is a rule for
+// every pair of pages, as in the puzzle, so that every update has a single right order
+fn generate_rules_and_updates(size: usize, rng: &mut Rng) -> String {
+	let mut pages: Vec<i64> = (10..=99).collect();
+	rng.shuffle(&mut pages);
+	pages.truncate(49);
+
+	let mut rules: Vec<(i64, i64)> = (0..pages.len())
+		.flat_map(|i| pages[i + 1..].iter().map(move |after| (i, *after)))
+		.map(|(i, after)| (pages[i], after))
+		.collect();
+	rng.shuffle(&mut rules);
+
+	let mut text: String = rules.iter().map(|(before, after)| format!("{}|{}\n", before, after)).collect();
+	text.push('\n');
+
+	for _ in 0..size {
+		let mut update = pages.clone();
+		rng.shuffle(&mut update);
+		update.truncate(2 * rng.below(10) + 5);
+		if rng.chance(0.5) {
+			update.sort_by_key(|page| pages.iter().position(|p| p == page));
+		}
+		text += &(update.iter().map(|page| page.to_string()).collect::<Vec<_>>().join(",") + "\n");
+	}
+
+	text
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+	const DAY: u8 = 5;
+
+	type Input = RulesAndUpdates;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		let (rules, manuals) = input;
+		valid_manuals_middle_page_sum(rules, manuals).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		let (rules, manuals) = input;
+		corrected_manuals_middle_page_sum(rules, manuals).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_rules_and_updates(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day5::parse(test_input_1()).unwrap();
+		assert_eq!(Day5::part_1(&input), Answer::Number(143));
+		assert_eq!(Day5::part_2(&input), Answer::Number(123));
+	}
+}
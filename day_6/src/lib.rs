@@ -0,0 +1,328 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::{Dir, Pos};
+use aoc_common::grid::{find_in_text, Grid};
+use aoc_common::parse::{first_problem, ParseError};
+use aoc_common::render::{char_palette, Image, Rgb};
+use aoc_common::rng::Rng;
+use aoc_common::simulation::Simulation;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::stats;
+use std::collections::HashSet;
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"....#.....\n",
+		".........#\n",
+		"..........\n",
+		"..#.......\n",
+		".......#..\n",
+		"..........\n",
+		".#..^.....\n",
+		"........#.\n",
+		"#.........\n",
+		"......#...\n",
+    )
+}
+
+// returns the map, with the guard replaced by an empty position, and the guard position
+pub fn parse_input(input: &str) -> Result<(Grid<char>, Pos), ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+// parse_input, going on after a problem to report all of them
+pub fn validate_input(input: &str) -> Result<(Grid<char>, Pos), Vec<ParseError>> {
+	let map = Grid::validate(input, "'.', '#' or '^'", |c| matches!(c, '.' | '#' | '^').then_some(c));
+	let mut problems = map.as_ref().err().cloned().unwrap_or_default();
+
+	// found from the text, so that they are reported along with the map's problems
+	let guards = find_in_text(input, '^');
+	if guards.is_empty() {
+		problems.push(ParseError::at_end(input, "a guard '^' in the map"));
+	}
+	for pos in guards.iter().skip(1) {
+		problems.push(ParseError::at_cell(*pos, '^', "a single guard in the map"));
+	}
+
+	let (Ok(mut map), [guard_pos]) = (map, guards.as_slice()) else {
+		return Err(problems);
+	};
+	map[*guard_pos] = '.';
+	Ok((map, *guard_pos))
+}
+
+// every position the guard walks on before leaving the map, which it has to leave, see test_loop
+pub fn get_visited_positions(map: &Grid<char>, mut guard_pos: Pos) -> HashSet<Pos> {
+	let mut visited_positions: HashSet<Pos> = HashSet::new();
+	let mut guard_dir = Dir::Up;
+
+	// Let's assume that the guard will not get stuck in a loop
+	loop {
+		visited_positions.insert(guard_pos);
+
+		let next_pos = guard_pos.step(guard_dir);
+		let Some(&next_symbol) = map.get(next_pos) else {break};
+		if next_symbol != '#' {
+			guard_pos = next_pos
+		} else {
+			guard_dir = guard_dir.turn_right();
+		}
+	}
+
+	visited_positions
+}
+
+// true when the guard walks in a loop instead of leaving the map
+pub fn test_loop(map: &Grid<char>, mut guard_pos: Pos) -> bool {
+	let mut visited_positions: HashSet<(Pos, Dir)> = HashSet::new();
+	let mut guard_dir = Dir::Up;
+
+	loop {
+		if !visited_positions.insert((guard_pos, guard_dir)) {
+			// the guard was in this state already, loop detected
+			return true
+		}
+
+		let next_pos = guard_pos.step(guard_dir);
+		let Some(&next_symbol) = map.get(next_pos) else {return false};
+		if next_symbol != '#' {
+			guard_pos = next_pos
+		} else {
+			guard_dir = guard_dir.turn_right();
+		}
+	}
+}
+
+// this is really slow, probably don't need to check every position...
+pub fn get_num_loop_obstacles(mut map: Grid<char>, guard_pos: Pos) -> usize {
+	let mut possible_loop_obstacles = get_visited_positions(&map, guard_pos);
+	// the guard is standing there
+	possible_loop_obstacles.remove(&guard_pos);
+	stats::record("obstacle positions tried", possible_loop_obstacles.len() as u64);
+	let mut loop_obstacles_count = 0;
+
+	for possible_loop_obstacle in possible_loop_obstacles {
+		map[possible_loop_obstacle] = '#';
+
+		if test_loop(&map, guard_pos) {
+			loop_obstacles_count += 1
+		}
+
+		map[possible_loop_obstacle] = '.';
+	}
+
+	loop_obstacles_count
+}
+
+// reference for get_num_loop_obstacles: tries every empty position, not only the ones on the guard's path
+pub fn get_num_loop_obstacles_everywhere(mut map: Grid<char>, guard_pos: Pos) -> usize {
+	let empty_positions: Vec<Pos> = map.find_all(&'.')
+		.filter(|pos| *pos != guard_pos)
+		.collect();
+
+	empty_positions.into_iter()
+		.filter(|pos| {
+			map[*pos] = '#';
+			let loops = test_loop(&map, guard_pos);
+			map[*pos] = '.';
+			loops
+		})
+		.count()
+}
+
+// the guard's path over the lab
+fn render_visited_positions(map: &Grid<char>, guard_pos: Pos) -> Image {
+	let mut image = Image::from_grid(map, char_palette(&[('#', Rgb::WHITE)], Rgb::DARK_GREY));
+	if !test_loop(map, guard_pos) {
+		image.tint(get_visited_positions(map, guard_pos), Rgb::YELLOW);
+	}
+	image.set(guard_pos, Rgb::RED);
+	image
+}
+
+// the walk of get_visited_positions, one move or turn per step
+struct GuardWalk {
+	map: Grid<char>,
+	guard_pos: Pos,
+	guard_dir: Dir,
+	visited_positions: HashSet<Pos>,
+	num_steps: usize,
+	left_map: bool,
+}
+
+impl Simulation for GuardWalk {
+	fn step(&mut self) -> bool {
+		if self.left_map {
+			return false;
+		}
+
+		let next_pos = self.guard_pos.step(self.guard_dir);
+		match self.map.get(next_pos) {
+			None => self.left_map = true,
+			Some('#') => self.guard_dir = self.guard_dir.turn_right(),
+			Some(_) => {
+				self.guard_pos = next_pos;
+				self.visited_positions.insert(next_pos);
+			}
+		}
+		self.num_steps += 1;
+		true
+	}
+
+	fn frame(&self) -> Grid<char> {
+		let mut frame = self.map.clone();
+		for pos in &self.visited_positions {
+			frame[*pos] = 'X';
+		}
+		if !self.left_map {
+			frame[self.guard_pos] = self.guard_dir.arrow();
+		}
+		frame
+	}
+
+	fn status(&self) -> String {
+		format!("step {}, {} positions visited", self.num_steps, self.visited_positions.len())
+	}
+}
+
+// a `size` by `size` lab, with a guard that leaves it without getting stuck in a loop
+fn generate_lab(size: usize, rng: &mut Rng) -> String {
+	let size = size.max(2) as i32;
+	loop {
+		let mut map = Grid::new(size, size, '.');
+		for pos in map.positions().collect::<Vec<_>>() {
+			if rng.chance(0.04) {
+				map[pos] = '#';
+			}
+		}
+
+		let guard_pos = Pos::new(rng.below(size as usize) as i32, rng.below(size as usize) as i32);
+		map[guard_pos] = '.';
+		if test_loop(&map, guard_pos) {
+			continue;
+		}
+
+		map[guard_pos] = '^';
+		return map.to_string();
+	}
+}
+
+// the puzzle's guard always leaves the lab, there's no path to count or obstacle to add otherwise
+fn guard_never_leaves() -> Answer {
+	Answer::Failed("the guard walks in a loop without an added obstacle".to_string())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+	const DAY: u8 = 6;
+	const REFERENCE_PARTS: &'static [u8] = &[2];
+
+	type Input = (Grid<char>, Pos);
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		let (map, guard_pos) = input;
+		if test_loop(map, *guard_pos) {
+			return guard_never_leaves();
+		}
+		get_visited_positions(map, *guard_pos).len().into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		let (map, guard_pos) = input;
+		if test_loop(map, *guard_pos) {
+			return guard_never_leaves();
+		}
+		get_num_loop_obstacles(map.clone(), *guard_pos).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_lab(size, rng))
+	}
+
+	fn render(input: &Self::Input) -> Vec<(&'static str, Image)> {
+		let (map, guard_pos) = input;
+		vec![("visited", render_visited_positions(map, *guard_pos))]
+	}
+
+	fn reference(input: &Self::Input, _part: u8) -> Answer {
+		let (map, guard_pos) = input;
+		if test_loop(map, *guard_pos) {
+			return guard_never_leaves();
+		}
+		get_num_loop_obstacles_everywhere(map.clone(), *guard_pos).into()
+	}
+
+	fn simulation(input: &Self::Input, _part: u8) -> Option<Box<dyn Simulation>> {
+		let (map, guard_pos) = input;
+		Some(Box::new(GuardWalk {
+			map: map.clone(),
+			guard_pos: *guard_pos,
+			guard_dir: Dir::Up,
+			visited_positions: HashSet::from([*guard_pos]),
+			num_steps: 0,
+			left_map: false,
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aoc_common::diff::find_divergence;
+
+	#[test]
+	fn example_1() {
+		let input = Day6::parse(test_input_1()).unwrap();
+		assert_eq!(Day6::part_1(&input), Answer::Number(41));
+		assert_eq!(Day6::part_2(&input), Answer::Number(6));
+	}
+
+	#[test]
+	fn obstacle_not_on_the_guard() {
+		// an obstacle where the guard starts would trap it between the four walls around (1, 1)
+		let input = Day6::parse(concat!(
+			"##..\n",
+			"#.#.\n",
+			".^#.\n",
+			"....\n",
+		)).unwrap();
+		assert_eq!(Day6::part_2(&input), Answer::Number(0));
+		assert_eq!(Day6::reference(&input, 2), Answer::Number(0));
+	}
+
+	#[test]
+	fn guard_in_a_loop() {
+		let lab = concat!(
+			".#..\n",
+			"...#\n",
+			"#^..\n",
+			"..#.\n",
+		);
+		assert_eq!(Day6::check(lab), vec![]);
+		let input = Day6::parse(lab).unwrap();
+		assert!(Day6::part_1(&input).is_failed());
+		assert!(Day6::part_2(&input).is_failed());
+	}
+
+	#[test]
+	fn reference_part_2() {
+		assert_eq!(find_divergence::<Day6>(2, 12, 10), Ok(None));
+	}
+}
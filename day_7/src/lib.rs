@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+
+use aoc_common::parse::{lines, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"190: 10 19\n",
+		"3267: 81 40 27\n",
+		"83: 17 5\n",
+		"156: 15 6\n",
+		"7290: 6 8 6 15\n",
+		"161011: 16 10 13\n",
+		"192: 17 8 14\n",
+		"21037: 9 7 18 13\n",
+		"292: 11 6 16 20\n",
+	)
+}
+
+// operands will be in reverse order for efficient pop
+pub fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+	lines(input).map(|line| {
+		let (result, operands) = line.split_once(line.text, ": ", "an equation like \"190: 10 19\"")?;
+		let result = line.parse(result, "a test value")?;
+		let mut operands = operands.split(' ')
+			.map(|x| line.parse(x, "an operand"))
+			.collect::<Result<Vec<u64>, _>>()?;
+		operands.reverse();
+		Ok((result, operands))
+	}).collect()
+}
+
+// the test value when + and * can combine the operands, reversed as parse_input returns them, into it,
+// 0 otherwise
+pub fn get_equation_result_if_correct(result: u64, operands: &[u64], current_value: u64) -> u64 {
+	if current_value > result {
+		return 0
+	}
+
+	if let Some((next_operand, other_operands)) = operands.split_last() {
+		let result_mul = get_equation_result_if_correct(result, other_operands, current_value * next_operand);
+		if result_mul > 0 {
+			result_mul
+		} else {
+			get_equation_result_if_correct(result, other_operands, current_value + next_operand)
+		}
+	} else if current_value == result { result } else { 0 }
+}
+
+fn u64_concatenation(a: u64, b: u64) -> u64 {
+	let mut b_copy = b;
+	let mut a_copy = a;
+	while b_copy > 0 {
+		a_copy *= 10;
+		b_copy /= 10;
+	}
+	a_copy + b
+}
+
+pub fn get_equation_result_if_correct_concatenation_allowed(result: u64, operands: &[u64], current_value: u64) -> u64 {
+	if current_value > result {
+		return 0
+	}
+
+	if let Some((next_operand, other_operands)) = operands.split_last() {
+		let result_mul = get_equation_result_if_correct_concatenation_allowed(result, other_operands, current_value * next_operand);
+		if result_mul > 0 {
+			return result_mul
+		}
+
+		let result_concat = get_equation_result_if_correct_concatenation_allowed(result, other_operands, u64_concatenation(current_value, *next_operand));
+		if result_concat > 0 {
+			return result_concat
+		}
+
+		get_equation_result_if_correct_concatenation_allowed(result, other_operands, current_value + next_operand)
+	} else if current_value == result { result } else { 0 }
+}
+
+// the sum of the test values of the equations that get_result_fn can make true
+pub fn sum_of_correct_equations<F>(equations: &[(u64, Vec<u64>)], get_result_fn: F) -> u64
+where
+	F: Fn(u64, &[u64], u64) -> u64,
+{
+	equations.iter()
+		.map(|(result, operands)| get_result_fn(*result, operands, 0))
+		.sum()
+}
+
+// `size` equations of 2 to 8 operands below 100, most of which can be made true. The operands are
+// small enough for every combination to fit in a u64
+fn generate_equations(size: usize, rng: &mut Rng) -> String {
+	(0..size)
+		.map(|_| {
+			let operands: Vec<u64> = (0..rng.between(2, 8)).map(|_| rng.between(1, 99) as u64).collect();
+			let result = if rng.chance(0.7) {
+				operands[1..].iter().fold(operands[0], |value, operand| match rng.below(3) {
+					0 => value + operand,
+					1 => value * operand,
+					_ => format!("{}{}", value, operand).parse().unwrap(),
+				})
+			} else {
+				rng.between(1, 1_000_000) as u64
+			};
+
+			let operands = operands.iter().map(|operand| operand.to_string()).collect::<Vec<_>>().join(" ");
+			format!("{}: {}\n", result, operands)
+		})
+		.collect()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+	const DAY: u8 = 7;
+
+	type Input = Vec<(u64, Vec<u64>)>;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		sum_of_correct_equations(input, get_equation_result_if_correct).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		sum_of_correct_equations(input, get_equation_result_if_correct_concatenation_allowed).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_equations(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day7::parse(test_input_1()).unwrap();
+		assert_eq!(Day7::part_1(&input), Answer::Number(3749));
+		assert_eq!(Day7::part_2(&input), Answer::Number(11387));
+	}
+}
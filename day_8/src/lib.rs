@@ -0,0 +1,175 @@
+#![allow(dead_code)]
+
+use aoc_common::geometry::Pos;
+use aoc_common::grid::Grid;
+use aoc_common::parse::{first_problem, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use itertools::Itertools; // Just for unique() ;)
+use std::collections::HashMap;
+
+// the positions of the antennas of each frequency
+pub type Antennas = HashMap<char, Vec<Pos>>;
+
+fn test_input_1() -> &'static str {
+	concat!(
+		"..........\n",
+		"..........\n",
+		"..........\n",
+		"....a.....\n",
+		"........a.\n",
+		".....a....\n",
+		"..........\n",
+		"..........\n",
+		"..........\n",
+		"..........\n"
+	)
+}
+
+fn test_input_2() -> &'static str {
+	concat!(
+		"............\n",
+		"........0...\n",
+		".....0......\n",
+		".......0....\n",
+		"....0.......\n",
+		"......A.....\n",
+		"............\n",
+		"............\n",
+		"........A...\n",
+		".........A..\n",
+		"............\n",
+		"............"
+	)
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+	validate_input(input).map_err(first_problem)
+}
+
+pub fn validate_input(input: &str) -> Result<Grid<char>, Vec<ParseError>> {
+	Grid::validate(input, "'.' or an antenna frequency (letter or digit)", |c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c))
+}
+
+pub fn get_freq_to_antennas(map: &Grid<char>) -> Antennas {
+	let mut freq_to_antennas: Antennas = HashMap::new();
+
+	map.iter()
+		.filter(|(_pos, c)| **c != '.')
+		.for_each(|(pos, c)| freq_to_antennas.entry(*c).or_default().push(pos));
+
+	freq_to_antennas
+}
+
+// the positions on the map twice as far from one antenna as from another of the same frequency
+pub fn count_anti_nodes(map: &Grid<char>, freq_to_antennas: &Antennas) -> usize {
+	freq_to_antennas.values()
+		.flat_map(|antennas| {
+			antennas.iter().enumerate().flat_map(move |(i, &a1)| {
+				antennas.iter().skip(i + 1).map(move |&a2| (a1, a2))
+			})
+		})
+		.flat_map(|(a1, a2)| {
+			let diff = a2 - a1;
+			[a1 - diff, a2 + diff]
+		})
+		.filter(|pos| map.in_bounds(*pos))
+		.unique()
+		.count()
+}
+
+// the positions on the map in line with two antennas of the same frequency
+pub fn count_line_anti_nodes(map: &Grid<char>, freq_to_antennas: &Antennas) -> usize {
+	freq_to_antennas.values()
+		.flat_map(|antennas| {
+			antennas.iter().enumerate().flat_map(move |(i, &a1)| {
+				antennas.iter().skip(i + 1).map(move |&a2| (a1, a2))
+			})
+		})
+		.flat_map(|(a1, a2)| {
+			let diff = a2 - a1;
+			std::iter::successors(Some(a1), move |&pos| {
+				let next = pos + diff;
+				if map.in_bounds(next) { Some(next) } else { None }
+			})
+			.chain(std::iter::successors(Some(a2), move |&pos| {
+				let next = pos - diff;
+				if map.in_bounds(next) { Some(next) } else { None }
+			}))
+		})
+		.unique()
+		.count()
+}
+
+// a `size` by `size` map with a few antennas of each frequency
+fn generate_antennas(size: usize, rng: &mut Rng) -> String {
+	let frequencies: Vec<char> = ('0'..='9').chain('a'..='z').chain('A'..='Z').collect();
+	let size = size.max(1) as i32;
+	let mut map = Grid::new(size, size, '.');
+
+	for _ in 0..(size * size / 25).max(2) {
+		let pos = Pos::new(rng.below(size as usize) as i32, rng.below(size as usize) as i32);
+		map[pos] = frequencies[rng.below(frequencies.len().min(size as usize / 3 + 1))];
+	}
+
+	map.to_string()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+	const DAY: u8 = 8;
+
+	type Input = (Grid<char>, Antennas);
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		let map = parse_input(input)?;
+		let freq_to_antennas = get_freq_to_antennas(&map);
+		Ok((map, freq_to_antennas))
+	}
+
+	fn check(input: &str) -> Vec<ParseError> {
+		validate_input(input).err().unwrap_or_default()
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		let (map, antennas) = input;
+		count_anti_nodes(map, antennas).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		let (map, antennas) = input;
+		count_line_anti_nodes(map, antennas).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_antennas(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day8::parse(test_input_1()).unwrap();
+		assert_eq!(Day8::part_1(&input), Answer::Number(4));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day8::parse(test_input_2()).unwrap();
+		assert_eq!(Day8::part_1(&input), Answer::Number(14));
+		assert_eq!(Day8::part_2(&input), Answer::Number(34));
+	}
+}
@@ -0,0 +1,193 @@
+#![allow(dead_code)]
+
+use aoc_common::parse::{lines, ParseError};
+use aoc_common::rng::Rng;
+use aoc_common::solution::{Answer, Solution};
+use std::ops::RangeInclusive;
+
+// one entry per block, with the id of the file it belongs to or None when it is free
+pub type DiskMap = Vec<Option<usize>>;
+// a run of blocks of the same file, or of free blocks
+pub type DiskZone = (Option<usize>, usize); // (Option(value), len)
+
+fn test_input_1() -> &'static str {
+	"12345"
+}
+
+fn test_input_2() -> &'static str {
+	"2333133121414131402"
+}
+
+// the puzzle's notation, a digit per file block and a dot per free block
+pub fn disk_map_to_string(disk_map: &DiskMap) -> String {
+	disk_map.iter().map(|o| o.map_or(".".to_string(), |v| v.to_string())).collect::<String>()
+}
+
+pub fn disk_zones_to_string(zones: &[DiskZone]) -> String {
+	zones.iter()
+		.flat_map(|(v_o, len)| std::iter::repeat_n(v_o.map_or(".".to_string(), |v| v.to_string()), *len))
+		.collect()
+}
+
+pub fn parse_input(input: &str) -> Result<DiskMap, ParseError> {
+	let mut input_lines = lines(input);
+	let line = input_lines.next().ok_or_else(|| ParseError::at_end(input, "a disk map"))?;
+	if let Some(extra_line) = input_lines.find(|line| !line.text.is_empty()) {
+		return Err(extra_line.error(extra_line.text, "the whole disk map on the first line"));
+	}
+
+	let digits = line.chars()
+		.map(|(column, c)| c.to_digit(10).ok_or_else(|| line.char_error(column, c, "a digit")))
+		.collect::<Result<Vec<u32>, _>>()?;
+
+	let disk_map = digits.into_iter().enumerate()
+		.flat_map(|(i, number)| {
+			let value = if i % 2 == 0 { Some(i / 2) } else { None };
+			std::iter::repeat_n(value, number as usize)
+		})
+		.collect();
+
+	Ok(disk_map)
+}
+
+pub fn get_disk_map_zones(map: &DiskMap) -> Vec<DiskZone> {
+	let mut zones = Vec::new();
+	let mut current_zone_value = map[0];
+	let mut current_zone_start = 0;
+
+	for (i, id_o) in map.iter().enumerate().skip(1) {
+		if *id_o != current_zone_value {
+			zones.push((current_zone_value, i - current_zone_start));
+
+			current_zone_value = *id_o;
+			current_zone_start = i;
+		}
+	}
+
+	zones.push((current_zone_value, map.len() - current_zone_start));
+	zones
+}
+
+fn find_last_block_index(map: &DiskMap, search_range: RangeInclusive<usize>) -> Option<usize> {
+	map[search_range].iter().rposition(|o| o.is_some())
+}
+
+// moves the blocks one at a time from the end of the disk to the leftmost free block
+pub fn compact_disk_blocks(input: &DiskMap) -> DiskMap {
+	let mut disk_map = input.clone();
+	let mut last_block_search_start_index = input.len() - 1;
+
+	for (i, id_o) in input.iter().enumerate() {
+		if i >= last_block_search_start_index {break}
+		if id_o.is_some() {continue}
+
+		let last_block_index = find_last_block_index(&disk_map, i..=last_block_search_start_index);
+		if last_block_index.is_none() {break}
+		let last_block_index = last_block_index.unwrap() + i; // we have to add i to the result because we are searching in a slice of the original array
+
+		disk_map.swap(i, last_block_index);
+		last_block_search_start_index = last_block_index
+	}
+
+	disk_map
+}
+
+// moves each whole file once, in decreasing id order, to the leftmost free zone that fits it
+pub fn compact_disk_files(input: &[DiskZone]) -> Vec<DiskZone> {
+	let mut disk_zones = input.to_vec();
+
+	for zone in input.iter().rev().filter(|(o, _)| o.is_some()) {
+		let available_zone_o = disk_zones.iter().enumerate()
+			.find(|(_, (v_o, len))| v_o.is_none() && len >= &zone.1);
+
+		if let Some((i_available, available_zone)) = available_zone_o {
+			let old_zone_pos = disk_zones.iter().position(|(v_o, _)| *v_o == zone.0).unwrap(); // this is pretty terrible
+			if old_zone_pos < i_available {continue} // I'm not sure why this works
+			disk_zones[i_available] = (None, available_zone.1 - zone.1);
+
+			disk_zones[old_zone_pos] = (None, zone.1);
+			disk_zones.insert(i_available, *zone);
+		}
+	}
+
+	disk_zones
+}
+
+
+// the sum of each block's position times the id of its file
+pub fn compute_disk_map_checksum(input: &DiskMap) -> usize {
+	input.iter()
+		.enumerate()
+		.map(|(i, id)| id.map_or(0, |v| v * i))
+		.sum()
+}
+
+pub fn compute_disc_zones_checksum(input: &[DiskZone]) -> usize {
+	input.iter()
+		.flat_map(|(v_o, len)| std::iter::repeat_n(v_o, *len))
+		.enumerate()
+		.map(|(i, id)| id.map_or(0, |v| v * i))
+		.sum()
+}
+
+// a disk map of `size` digits, files of 1 to 9 blocks separated by 0 to 9 free blocks
+fn generate_disk_map(size: usize, rng: &mut Rng) -> String {
+	// ends with a file
+	let size = size.max(1) | 1;
+	(0..size)
+		.map(|i| if i % 2 == 0 { rng.between(1, 9) } else { rng.between(0, 9) })
+		.map(|digit| char::from_digit(digit as u32, 10).unwrap())
+		.collect::<String>() + "\n"
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+	const DAY: u8 = 9;
+
+	type Input = DiskMap;
+	type Params = ();
+
+	fn parse(input: &str) -> Result<Self::Input, ParseError> {
+		parse_input(input)
+	}
+
+	fn part_1(input: &Self::Input) -> Answer {
+		compute_disk_map_checksum(&compact_disk_blocks(input)).into()
+	}
+
+	fn part_2(input: &Self::Input) -> Answer {
+		compute_disc_zones_checksum(&compact_disk_files(&get_disk_map_zones(input))).into()
+	}
+
+	fn example(n: usize) -> Option<&'static str> {
+		match n {
+			1 => Some(test_input_1()),
+			2 => Some(test_input_2()),
+			_ => None
+		}
+	}
+
+	fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+		Some(generate_disk_map(size, rng))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn example_1() {
+		let input = Day9::parse(test_input_1()).unwrap();
+		assert_eq!(Day9::part_1(&input), Answer::Number(60));
+		assert_eq!(Day9::part_2(&input), Answer::Number(132));
+	}
+
+	#[test]
+	fn example_2() {
+		let input = Day9::parse(test_input_2()).unwrap();
+		assert_eq!(Day9::part_1(&input), Answer::Number(1928));
+		assert_eq!(Day9::part_2(&input), Answer::Number(2858));
+	}
+}